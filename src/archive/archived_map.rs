@@ -0,0 +1,491 @@
+use core::borrow::Borrow;
+use core::hash::{BuildHasher, Hash};
+use core::iter::FusedIterator;
+use core::{fmt, mem, slice};
+
+use super::{ArchiveBuildHasher, Entry, Header, Plain};
+use crate::{utils, ArchiveError};
+
+/// A map that is read in place from the bytes written by
+/// [`ArrayMapFacade::archive_into`](crate::ArrayMapFacade::archive_into).
+///
+/// See the [module documentation](crate::archive) for more details.
+pub struct ArchivedMap<'a, K, V, const N: usize, B> {
+    /// The slots of the hash table, each one is an `u32` (little endian).
+    indices: &'a [u8],
+    entries: &'a [Entry<K, V>],
+    build_hasher: B,
+}
+
+impl<'a, K, V, const N: usize, B> ArchivedMap<'a, K, V, N, B>
+where
+    K: Plain + Hash + Eq,
+    V: Plain,
+    B: BuildHasher,
+{
+    /// Validates the bytes and creates a map that reads from them with the
+    /// hasher recreated from the seed recorded in the archive.
+    ///
+    /// The validation checks the header, the checksum, that the recreated
+    /// hasher produces the same hashes the archive has been written with and
+    /// that every entry can be found through the hash table.
+    ///
+    /// # Errors
+    ///
+    /// If the bytes are not a valid archive of a map with capacity `N` and the
+    /// same key and value types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::archive::{AlignedBuffer, ArchivedMap, SeededState};
+    /// use array_map::{ArchiveError, ArrayMap};
+    ///
+    /// let mut map: ArrayMap<u8, u8, 7, SeededState> =
+    ///     ArrayMap::with_hasher(SeededState::with_seeds(1, 2, 3, 4));
+    /// map.try_insert(1, 2).unwrap();
+    ///
+    /// let mut buffer = AlignedBuffer::<256>::new();
+    /// let size = map.archive_into(&mut buffer)?;
+    ///
+    /// let archived = ArchivedMap::<u8, u8, 7, SeededState>::from_bytes(&buffer[..size])?;
+    /// assert_eq!(archived.get(&1), Some(&2));
+    ///
+    /// // flipping a single bit is detected
+    /// buffer[size - 1] ^= 1;
+    /// assert_eq!(
+    ///     ArchivedMap::<u8, u8, 7, SeededState>::from_bytes(&buffer[..size]).err(),
+    ///     Some(ArchiveError::ChecksumMismatch)
+    /// );
+    /// # Ok::<_, array_map::ArchiveError>(())
+    /// ```
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, ArchiveError>
+    where
+        B: ArchiveBuildHasher,
+    {
+        let header = Header::read::<K, V>(bytes)?;
+
+        Self::from_bytes_with_hasher(bytes, B::from_seed(header.seed))
+    }
+
+    /// Validates the bytes and creates a map that reads from them with the
+    /// provided hasher.
+    ///
+    /// This allows to read archives with a hasher, that can not be recreated
+    /// from the recorded seed, as long as it produces the same hashes.
+    ///
+    /// # Errors
+    ///
+    /// If the bytes are not a valid archive of a map with capacity `N` and the
+    /// same key and value types, or if the archive has been written with a
+    /// different hasher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ahash::RandomState;
+    /// use array_map::archive::{AlignedBuffer, ArchivedMap, SeededState};
+    /// use array_map::{ArchiveError, ArrayMap};
+    ///
+    /// let mut map: ArrayMap<u8, u8, 7, SeededState> =
+    ///     ArrayMap::with_hasher(SeededState::with_seeds(1, 2, 3, 4));
+    /// map.try_insert(1, 2).unwrap();
+    ///
+    /// let mut buffer = AlignedBuffer::<256>::new();
+    /// let size = map.archive_into(&mut buffer)?;
+    ///
+    /// let archived = ArchivedMap::<u8, u8, 7, _>::from_bytes_with_hasher(
+    ///     &buffer[..size],
+    ///     RandomState::with_seeds(1, 2, 3, 4),
+    /// )?;
+    /// assert_eq!(archived.get(&1), Some(&2));
+    ///
+    /// assert_eq!(
+    ///     ArchivedMap::<u8, u8, 7, _>::from_bytes_with_hasher(
+    ///         &buffer[..size],
+    ///         RandomState::with_seeds(4, 3, 2, 1),
+    ///     )
+    ///     .err(),
+    ///     Some(ArchiveError::HasherMismatch)
+    /// );
+    /// # Ok::<_, array_map::ArchiveError>(())
+    /// ```
+    pub fn from_bytes_with_hasher(bytes: &'a [u8], build_hasher: B) -> Result<Self, ArchiveError> {
+        let header = Header::read::<K, V>(bytes)?;
+
+        if header.capacity != N {
+            return Err(ArchiveError::CapacityMismatch {
+                expected: N,
+                actual: header.capacity,
+            });
+        }
+
+        if header.len > N {
+            return Err(ArchiveError::Corrupted);
+        }
+
+        let size = super::archived_size::<K, V>(N, header.len);
+        if bytes.len() < size {
+            return Err(ArchiveError::BufferTooSmall {
+                required: size,
+                actual: bytes.len(),
+            });
+        }
+
+        let align = mem::align_of::<Entry<K, V>>();
+        if bytes.as_ptr().align_offset(align) != 0 {
+            return Err(ArchiveError::Misaligned { align });
+        }
+
+        let bytes = &bytes[..size];
+        if super::checksum(bytes) != super::read_u64(bytes, super::CHECKSUM_OFFSET) {
+            return Err(ArchiveError::ChecksumMismatch);
+        }
+
+        if super::fingerprint(&build_hasher) != header.fingerprint {
+            return Err(ArchiveError::HasherMismatch);
+        }
+
+        let entries_offset = super::entries_offset::<K, V>(N);
+        let indices = &bytes[super::HEADER_SIZE..super::HEADER_SIZE + N * 4];
+        // SAFETY: the bytes are aligned and large enough for `len` entries, and every
+        //         bit pattern is a valid `Plain` value
+        let entries = unsafe {
+            slice::from_raw_parts(
+                bytes.as_ptr().add(entries_offset).cast::<Entry<K, V>>(),
+                header.len,
+            )
+        };
+
+        let result = Self {
+            indices,
+            entries,
+            build_hasher,
+        };
+        result.validate_slots()?;
+
+        Ok(result)
+    }
+
+    /// Checks that every entry is referenced by exactly one slot, which can be
+    /// reached by probing from the slot the hash of the entry points to.
+    fn validate_slots(&self) -> Result<(), ArchiveError> {
+        let mut referenced = [false; N];
+        let mut occupied = 0;
+
+        for slot in 0..N {
+            let Some(index) = self.index_at(slot) else {
+                continue;
+            };
+
+            if index >= self.entries.len() || referenced[index] {
+                return Err(ArchiveError::Corrupted);
+            }
+
+            referenced[index] = true;
+            occupied += 1;
+
+            let hash = utils::make_hash::<K, K, B>(&self.build_hasher, &self.entries[index].key);
+            let mut probe = utils::adjust_hash::<N>(hash);
+            while probe != slot {
+                if self.index_at(probe).is_none() {
+                    return Err(ArchiveError::Corrupted);
+                }
+
+                probe = (probe + 1) % N;
+            }
+        }
+
+        if occupied == self.entries.len() {
+            Ok(())
+        } else {
+            Err(ArchiveError::Corrupted)
+        }
+    }
+
+    /// Returns the index of the entry stored in the slot, if it is occupied.
+    fn index_at(&self, slot: usize) -> Option<usize> {
+        let index = super::read_u32(self.indices, slot * 4);
+
+        if index == super::EMPTY {
+            None
+        } else {
+            Some(index as usize)
+        }
+    }
+
+    fn find<Q: ?Sized>(&self, key: &Q) -> Option<&'a Entry<K, V>>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        if self.entries.is_empty() {
+            return None;
+        }
+
+        let hash = utils::make_hash::<K, Q, B>(&self.build_hasher, key);
+        let start = utils::adjust_hash::<N>(hash);
+
+        for slot in (start..N).chain(0..start) {
+            let entry = &self.entries[self.index_at(slot)?];
+
+            if entry.key.borrow() == key {
+                return Some(entry);
+            }
+        }
+
+        None
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    #[must_use]
+    pub fn get<Q: ?Sized>(&self, key: &Q) -> Option<&'a V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.find(key).map(|entry| &entry.value)
+    }
+
+    /// Returns the key-value pair corresponding to the key.
+    #[must_use]
+    pub fn get_key_value<Q: ?Sized>(&self, key: &Q) -> Option<(&'a K, &'a V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.find(key).map(|entry| (&entry.key, &entry.value))
+    }
+
+    /// Returns `true` if the map contains a value for the specified key.
+    #[must_use]
+    pub fn contains_key<Q: ?Sized>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.find(key).is_some()
+    }
+}
+
+impl<'a, K, V, const N: usize, B> ArchivedMap<'a, K, V, N, B> {
+    /// Returns the number of entries in the map.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the map does not contain any entries.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the capacity of the map the archive has been written from.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the entry at the index, where the index is the position of the
+    /// entry in the iteration order of the archived map.
+    #[must_use]
+    pub fn get_index(&self, index: usize) -> Option<(&'a K, &'a V)> {
+        self.entries
+            .get(index)
+            .map(|entry| (&entry.key, &entry.value))
+    }
+
+    /// An iterator visiting all key-value pairs in the iteration order of the
+    /// map the archive has been written from.
+    #[must_use]
+    pub fn iter(&self) -> Iter<'a, K, V> {
+        Iter {
+            entries: self.entries.iter(),
+        }
+    }
+}
+
+impl<'a, K, V, const N: usize, B> IntoIterator for &ArchivedMap<'a, K, V, N, B> {
+    type IntoIter = Iter<'a, K, V>;
+    type Item = (&'a K, &'a V);
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K, V, const N: usize, B> fmt::Debug for ArchivedMap<'_, K, V, N, B>
+where
+    K: fmt::Debug,
+    V: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// An iterator over the entries of an [`ArchivedMap`].
+pub struct Iter<'a, K, V> {
+    entries: slice::Iter<'a, Entry<K, V>>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.entries.next().map(|entry| (&entry.key, &entry.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.entries.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Iter<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.entries
+            .next_back()
+            .map(|entry| (&entry.key, &entry.value))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<K, V> FusedIterator for Iter<'_, K, V> {}
+
+#[cfg(all(test, feature = "ahash"))]
+mod tests {
+    use core::hash::BuildHasherDefault;
+
+    use ahash::AHasher;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::archive::{AlignedBuffer, SeededState};
+    use crate::ext::IteratorExt;
+    use crate::{ArrayMap, IndexMap};
+
+    type Hasher = BuildHasherDefault<AHasher>;
+
+    #[test]
+    fn test_archived_array_map() {
+        let mut map: ArrayMap<u64, [u16; 3], 13, Hasher> = ArrayMap::default();
+        for i in 0..10_u16 {
+            map.try_insert(u64::from(i) * 7, [i; 3]).unwrap();
+        }
+
+        let mut buffer = AlignedBuffer::<512>::new();
+        let size = map.archive_into(&mut buffer).unwrap();
+        let archived =
+            ArchivedMap::<u64, [u16; 3], 13, Hasher>::from_bytes(&buffer[..size]).unwrap();
+
+        assert_eq!(archived.len(), 10);
+        for (key, value) in &map {
+            assert_eq!(archived.get(key), Some(value));
+        }
+        assert_eq!(archived.contains_key(&1), false);
+        assert!(archived.iter().eq(map.iter()));
+    }
+
+    #[test]
+    fn test_archived_index_map_keeps_order() {
+        let mut map: IndexMap<u32, u32, 5, Hasher> = IndexMap::default();
+        map.try_insert(4, 0).unwrap();
+        map.try_insert(2, 1).unwrap();
+        map.try_insert(9, 2).unwrap();
+
+        let mut buffer = AlignedBuffer::<512>::new();
+        let size = map.archive_into(&mut buffer).unwrap();
+        let archived = ArchivedMap::<u32, u32, 5, Hasher>::from_bytes(&buffer[..size]).unwrap();
+
+        assert_eq!(
+            archived.iter().try_collect::<[_; 3]>(),
+            Ok([(&4, &0), (&2, &1), (&9, &2)])
+        );
+        assert_eq!(archived.get_index(1), Some((&2, &1)));
+    }
+
+    #[test]
+    fn test_archived_map_recreates_seeded_hasher() {
+        let mut map: ArrayMap<u32, u32, 11, SeededState> =
+            ArrayMap::with_hasher(SeededState::with_seeds(5, 6, 7, 8));
+        for i in 0..8 {
+            map.try_insert(i, i * 2).unwrap();
+        }
+
+        let mut buffer = AlignedBuffer::<512>::new();
+        let size = map.archive_into(&mut buffer).unwrap();
+        let archived =
+            ArchivedMap::<u32, u32, 11, SeededState>::from_bytes(&buffer[..size]).unwrap();
+
+        assert_eq!(archived.build_hasher.seed(), [5, 6, 7, 8]);
+        for (key, value) in &map {
+            assert_eq!(archived.get(key), Some(value));
+        }
+        assert_eq!(
+            ArchivedMap::<u32, u32, 11, SeededState>::from_bytes_with_hasher(
+                &buffer[..size],
+                SeededState::with_seeds(8, 7, 6, 5)
+            )
+            .err(),
+            Some(ArchiveError::HasherMismatch)
+        );
+    }
+
+    #[test]
+    fn test_archived_map_validation() {
+        let mut map: ArrayMap<u32, u32, 5, Hasher> = ArrayMap::default();
+        map.try_insert(1, 1).unwrap();
+
+        let mut buffer = AlignedBuffer::<512>::new();
+        let size = map.archive_into(&mut buffer).unwrap();
+        let bytes = &buffer[..size];
+
+        assert_eq!(
+            ArchivedMap::<u32, u32, 7, Hasher>::from_bytes(bytes).err(),
+            Some(ArchiveError::CapacityMismatch {
+                expected: 7,
+                actual: 5
+            })
+        );
+        assert_eq!(
+            ArchivedMap::<u32, u64, 5, Hasher>::from_bytes(bytes).err(),
+            Some(ArchiveError::LayoutMismatch)
+        );
+        assert_eq!(
+            ArchivedMap::<u32, u32, 5, Hasher>::from_bytes(&buffer[1..=size]).err(),
+            Some(ArchiveError::InvalidHeader)
+        );
+        assert_eq!(
+            ArchivedMap::<u32, u32, 5, Hasher>::from_bytes(&bytes[..size - 1]).err(),
+            Some(ArchiveError::BufferTooSmall {
+                required: size,
+                actual: size - 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_archived_map_detects_corrupted_slots() {
+        let mut map: ArrayMap<u32, u32, 5, Hasher> = ArrayMap::default();
+        map.try_insert(1, 1).unwrap();
+
+        let mut buffer = AlignedBuffer::<512>::new();
+        let size = map.archive_into(&mut buffer).unwrap();
+
+        // reference the entry from every slot and fix the checksum
+        for slot in 0..5 {
+            let offset = super::super::HEADER_SIZE + slot * 4;
+            buffer[offset..offset + 4].copy_from_slice(&0_u32.to_le_bytes());
+        }
+        let checksum = super::super::checksum(&buffer[..size]);
+        buffer[super::super::CHECKSUM_OFFSET..super::super::HEADER_SIZE]
+            .copy_from_slice(&checksum.to_le_bytes());
+
+        assert_eq!(
+            ArchivedMap::<u32, u32, 5, Hasher>::from_bytes(&buffer[..size]).err(),
+            Some(ArchiveError::Corrupted)
+        );
+    }
+}
@@ -0,0 +1,253 @@
+//! A fixed binary layout for maps, that can be queried in place.
+//!
+//! Because the capacity of a map is known at compile time, a map can be
+//! written into a byte buffer with [`ArrayMapFacade::archive_into`] and later
+//! be read with [`ArchivedMap::from_bytes`] without deserializing it (for
+//! example directly from flash).
+//!
+//! The archive consists of a header, which records the capacity, the number
+//! of entries, the seed and a fingerprint of the hasher and a checksum,
+//! followed by the slots of the hash table and the entries in iteration
+//! order. The entries are stored in the native byte order, so an archive can
+//! only be read on a platform with the same byte order as the one it has been
+//! written on.
+//!
+//! Only maps with an [`ArchiveBuildHasher`] can be archived, because the
+//! hasher has to be recreated from the recorded seed to find the entries
+//! again. [`BuildHasherDefault`] has no seed and [`SeededState`] is a seeded
+//! `ahash` hasher, that remembers its seed.
+//!
+//! [`ArrayMapFacade::archive_into`]: crate::ArrayMapFacade::archive_into
+//!
+//! # Examples
+//!
+//! ```
+//! use array_map::archive::{AlignedBuffer, ArchivedMap, SeededState};
+//! use array_map::IndexMap;
+//!
+//! let mut map: IndexMap<u32, u64, 7, SeededState> =
+//!     IndexMap::with_hasher(SeededState::with_seeds(1, 2, 3, 4));
+//! map.try_insert(3, 30).unwrap();
+//! map.try_insert(1, 10).unwrap();
+//!
+//! // the buffer must be aligned for the entries
+//! let mut buffer = AlignedBuffer::<256>::new();
+//! let size = map.archive_into(&mut buffer)?;
+//!
+//! // the hasher is recreated from the seed in the archive
+//! let archived: ArchivedMap<u32, u64, 7, SeededState> = ArchivedMap::from_bytes(&buffer[..size])?;
+//! assert_eq!(archived.get(&3), Some(&30));
+//! assert_eq!(archived.contains_key(&2), false);
+//! assert_eq!(archived.iter().collect::<Vec<_>>(), vec![(&3, &30), (&1, &10)]);
+//! # Ok::<_, array_map::ArchiveError>(())
+//! ```
+mod archived_map;
+mod plain;
+#[cfg(feature = "ahash")]
+mod seeded_state;
+mod write;
+
+use core::hash::{BuildHasher, BuildHasherDefault, Hasher};
+use core::mem;
+use core::ops::{Deref, DerefMut};
+
+use crate::utils::FnvHasher;
+use crate::ArchiveError;
+
+pub use archived_map::*;
+pub use plain::*;
+#[cfg(feature = "ahash")]
+pub use seeded_state::*;
+
+/// A [`BuildHasher`] that can be recreated from a recorded seed.
+///
+/// The seed is stored in the archive, so that the archive can be read with
+/// the same hasher it has been written with.
+pub trait ArchiveBuildHasher: BuildHasher {
+    /// Returns the seed of the [`BuildHasher`].
+    #[must_use]
+    fn seed(&self) -> [u64; 4];
+
+    /// Creates a [`BuildHasher`] from a seed returned by
+    /// [`ArchiveBuildHasher::seed`].
+    #[must_use]
+    fn from_seed(seed: [u64; 4]) -> Self;
+}
+
+impl<H: Default + Hasher> ArchiveBuildHasher for BuildHasherDefault<H> {
+    fn seed(&self) -> [u64; 4] {
+        [0; 4]
+    }
+
+    fn from_seed(_: [u64; 4]) -> Self {
+        Self::default()
+    }
+}
+
+/// A byte buffer, which is aligned for archives of the primitive [`Plain`]
+/// types and arrays of them.
+///
+/// # Examples
+///
+/// ```
+/// use array_map::archive::AlignedBuffer;
+///
+/// let buffer = AlignedBuffer::<64>::new();
+/// assert_eq!(buffer.len(), 64);
+/// assert_eq!(buffer.as_ptr().align_offset(16), 0);
+/// ```
+#[repr(C, align(16))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AlignedBuffer<const SIZE: usize>([u8; SIZE]);
+
+impl<const SIZE: usize> AlignedBuffer<SIZE> {
+    /// Creates a buffer filled with zeros.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self([0; SIZE])
+    }
+}
+
+impl<const SIZE: usize> Default for AlignedBuffer<SIZE> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const SIZE: usize> Deref for AlignedBuffer<SIZE> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const SIZE: usize> DerefMut for AlignedBuffer<SIZE> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+const MAGIC: [u8; 4] = *b"AMAP";
+const VERSION: u32 = 1;
+/// Is stored in native byte order to detect archives from other platforms.
+const BYTE_ORDER_MARK: u32 = 0x0102_0304;
+/// The key, which is hashed to detect a mismatching hasher.
+const FINGERPRINT_KEY: u64 = 0x9e37_79b9_7f4a_7c15;
+/// Marks a vacant slot in the hash table.
+const EMPTY: u32 = u32::MAX;
+
+const HEADER_SIZE: usize = 88;
+const CHECKSUM_OFFSET: usize = 80;
+
+/// The archived representation of an entry.
+#[repr(C)]
+pub(crate) struct Entry<K, V> {
+    pub(crate) key: K,
+    pub(crate) value: V,
+}
+
+/// Returns the number of bytes needed to archive `len` entries of a map with
+/// the provided capacity.
+#[must_use]
+pub const fn archived_size<K, V>(capacity: usize, len: usize) -> usize {
+    entries_offset::<K, V>(capacity) + len * mem::size_of::<Entry<K, V>>()
+}
+
+const fn entries_offset<K, V>(capacity: usize) -> usize {
+    let align = mem::align_of::<Entry<K, V>>();
+
+    (HEADER_SIZE + capacity * mem::size_of::<u32>()).next_multiple_of(align)
+}
+
+/// The header at the start of each archive.
+struct Header {
+    capacity: usize,
+    len: usize,
+    fingerprint: u64,
+    seed: [u64; 4],
+}
+
+impl Header {
+    fn write<K, V>(&self, bytes: &mut [u8]) -> Result<(), ArchiveError> {
+        let align = u32::try_from(mem::align_of::<Entry<K, V>>())
+            .map_err(|_| ArchiveError::LayoutMismatch)?;
+
+        bytes[0..4].copy_from_slice(&MAGIC);
+        bytes[4..8].copy_from_slice(&VERSION.to_le_bytes());
+        bytes[8..12].copy_from_slice(&BYTE_ORDER_MARK.to_ne_bytes());
+        bytes[12..16].copy_from_slice(&align.to_le_bytes());
+        bytes[16..24].copy_from_slice(&(self.capacity as u64).to_le_bytes());
+        bytes[24..32].copy_from_slice(&(self.len as u64).to_le_bytes());
+        bytes[32..40].copy_from_slice(&(mem::size_of::<Entry<K, V>>() as u64).to_le_bytes());
+        bytes[40..48].copy_from_slice(&self.fingerprint.to_le_bytes());
+
+        for (i, seed) in self.seed.iter().enumerate() {
+            bytes[48 + i * 8..56 + i * 8].copy_from_slice(&seed.to_le_bytes());
+        }
+
+        Ok(())
+    }
+
+    fn read<K, V>(bytes: &[u8]) -> Result<Self, ArchiveError> {
+        if bytes.len() < HEADER_SIZE {
+            return Err(ArchiveError::BufferTooSmall {
+                required: HEADER_SIZE,
+                actual: bytes.len(),
+            });
+        }
+
+        if bytes[0..4] != MAGIC || read_u32(bytes, 4) != VERSION {
+            return Err(ArchiveError::InvalidHeader);
+        }
+
+        if bytes[8..12] != BYTE_ORDER_MARK.to_ne_bytes()
+            || read_u32(bytes, 12) as usize != mem::align_of::<Entry<K, V>>()
+            || read_u64(bytes, 32) != mem::size_of::<Entry<K, V>>() as u64
+        {
+            return Err(ArchiveError::LayoutMismatch);
+        }
+
+        let capacity = usize::try_from(read_u64(bytes, 16)).map_err(|_| ArchiveError::Corrupted)?;
+        let len = usize::try_from(read_u64(bytes, 24)).map_err(|_| ArchiveError::Corrupted)?;
+
+        let mut seed = [0; 4];
+        for (i, seed) in seed.iter_mut().enumerate() {
+            *seed = read_u64(bytes, 48 + i * 8);
+        }
+
+        Ok(Self {
+            capacity,
+            len,
+            fingerprint: read_u64(bytes, 40),
+            seed,
+        })
+    }
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    let mut result = [0; 4];
+    result.copy_from_slice(&bytes[offset..offset + 4]);
+    u32::from_le_bytes(result)
+}
+
+fn read_u64(bytes: &[u8], offset: usize) -> u64 {
+    let mut result = [0; 8];
+    result.copy_from_slice(&bytes[offset..offset + 8]);
+    u64::from_le_bytes(result)
+}
+
+/// Computes the checksum (FNV-1a) of an archive, which covers everything
+/// except for the checksum itself.
+fn checksum(bytes: &[u8]) -> u64 {
+    let mut hasher = FnvHasher::default();
+
+    hasher.write(&bytes[..CHECKSUM_OFFSET]);
+    hasher.write(&bytes[HEADER_SIZE..]);
+
+    hasher.finish()
+}
+
+fn fingerprint<B: BuildHasher>(build_hasher: &B) -> u64 {
+    crate::utils::make_hash::<u64, u64, B>(build_hasher, &FINGERPRINT_KEY)
+}
@@ -0,0 +1,17 @@
+/// A marker trait for types that can be stored in an archived map.
+///
+/// # Safety
+///
+/// The type must not contain pointers or padding and every bit pattern must
+/// be a valid value of the type, so that it can be read from arbitrary bytes.
+pub unsafe trait Plain: Copy + 'static {}
+
+macro_rules! impl_plain {
+    ( $( $t:ty ),* ) => {
+        $( unsafe impl Plain for $t {} )*
+    };
+}
+
+impl_plain!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64, ());
+
+unsafe impl<T: Plain, const N: usize> Plain for [T; N] {}
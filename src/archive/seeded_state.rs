@@ -0,0 +1,59 @@
+use core::fmt;
+use core::hash::BuildHasher;
+
+use ahash::{AHasher, RandomState};
+
+use super::ArchiveBuildHasher;
+
+/// A seeded [`RandomState`], that remembers its seed, so that it can be
+/// recorded in an archive.
+///
+/// # Examples
+///
+/// ```
+/// use array_map::archive::{ArchiveBuildHasher, SeededState};
+///
+/// let state = SeededState::with_seeds(1, 2, 3, 4);
+/// assert_eq!(state.seed(), [1, 2, 3, 4]);
+/// ```
+#[derive(Clone)]
+pub struct SeededState {
+    seed: [u64; 4],
+    state: RandomState,
+}
+
+impl SeededState {
+    /// Creates a [`SeededState`] from four seeds, like
+    /// [`RandomState::with_seeds`].
+    #[must_use]
+    pub const fn with_seeds(k0: u64, k1: u64, k2: u64, k3: u64) -> Self {
+        Self {
+            seed: [k0, k1, k2, k3],
+            state: RandomState::with_seeds(k0, k1, k2, k3),
+        }
+    }
+}
+
+impl BuildHasher for SeededState {
+    type Hasher = AHasher;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        self.state.build_hasher()
+    }
+}
+
+impl ArchiveBuildHasher for SeededState {
+    fn seed(&self) -> [u64; 4] {
+        self.seed
+    }
+
+    fn from_seed([k0, k1, k2, k3]: [u64; 4]) -> Self {
+        Self::with_seeds(k0, k1, k2, k3)
+    }
+}
+
+impl fmt::Debug for SeededState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SeededState").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,153 @@
+use core::hash::{BuildHasher, Hash};
+use core::{mem, ptr};
+
+use super::{ArchiveBuildHasher, Entry, Header, Plain};
+use crate::raw::RawTableIter;
+use crate::{utils, ArchiveError, ArrayMapFacade};
+
+impl<K, V, R, B> ArrayMapFacade<K, V, R, B>
+where
+    K: Plain + Hash + Eq,
+    V: Plain,
+    R: RawTableIter<(K, V)>,
+    B: BuildHasher,
+{
+    /// Returns the number of bytes [`ArrayMapFacade::archive_into`] will
+    /// write.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::hash::BuildHasherDefault;
+    ///
+    /// use ahash::AHasher;
+    /// use array_map::archive;
+    /// use array_map::ArrayMap;
+    ///
+    /// let mut map: ArrayMap<u32, u32, 7, BuildHasherDefault<AHasher>> = ArrayMap::default();
+    /// map.try_insert(1, 2).unwrap();
+    ///
+    /// assert_eq!(map.archived_size(), archive::archived_size::<u32, u32>(7, 1));
+    /// ```
+    #[must_use]
+    pub fn archived_size(&self) -> usize {
+        super::archived_size::<K, V>(self.capacity(), self.len())
+    }
+}
+
+impl<K, V, R, B> ArrayMapFacade<K, V, R, B>
+where
+    K: Plain + Hash + Eq,
+    V: Plain,
+    R: RawTableIter<(K, V)>,
+    B: ArchiveBuildHasher,
+{
+    /// Writes the map into the buffer and returns the number of bytes written.
+    ///
+    /// The written bytes can be read with
+    /// [`ArchivedMap::from_bytes`](crate::archive::ArchivedMap::from_bytes).
+    /// The entries are stored in the iteration order of the map, so an
+    /// archived [`IndexMap`](crate::IndexMap) keeps its order, and the seed of
+    /// the hasher is recorded, so that it can be recreated.
+    ///
+    /// # Errors
+    ///
+    /// If the buffer is smaller than [`ArrayMapFacade::archived_size`], not
+    /// aligned for the entries or the layout of the map can not be
+    /// represented in an archive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::hash::BuildHasherDefault;
+    ///
+    /// use ahash::AHasher;
+    /// use array_map::archive::AlignedBuffer;
+    /// use array_map::{ArchiveError, ArrayMap};
+    ///
+    /// let mut map: ArrayMap<u8, u8, 7, BuildHasherDefault<AHasher>> = ArrayMap::default();
+    /// map.try_insert(1, 2).unwrap();
+    ///
+    /// let mut buffer = AlignedBuffer::<256>::new();
+    /// assert_eq!(map.archive_into(&mut buffer)?, map.archived_size());
+    ///
+    /// assert_eq!(
+    ///     map.archive_into(&mut buffer[..8]),
+    ///     Err(ArchiveError::BufferTooSmall {
+    ///         required: map.archived_size(),
+    ///         actual: 8
+    ///     })
+    /// );
+    /// # Ok::<_, array_map::ArchiveError>(())
+    /// ```
+    pub fn archive_into(&self, buffer: &mut [u8]) -> Result<usize, ArchiveError> {
+        let capacity = self.capacity();
+        let size = self.archived_size();
+        let align = mem::align_of::<Entry<K, V>>();
+
+        if buffer.len() < size {
+            return Err(ArchiveError::BufferTooSmall {
+                required: size,
+                actual: buffer.len(),
+            });
+        }
+
+        if buffer.as_ptr().align_offset(align) != 0 {
+            return Err(ArchiveError::Misaligned { align });
+        }
+
+        // the slots are addressed with an u32, where `u32::MAX` marks an empty slot
+        if u32::try_from(capacity).map_or(true, |capacity| capacity == super::EMPTY) {
+            return Err(ArchiveError::LayoutMismatch);
+        }
+
+        let buffer = &mut buffer[..size];
+        buffer.fill(0);
+
+        let entries_offset = super::entries_offset::<K, V>(capacity);
+        let (header, payload) = buffer.split_at_mut(super::HEADER_SIZE);
+        let (indices, entries) = payload.split_at_mut(entries_offset - super::HEADER_SIZE);
+        indices.fill(0xFF);
+
+        for (index, (key, value)) in self.iter().enumerate() {
+            let offset = index * mem::size_of::<Entry<K, V>>();
+
+            // SAFETY: the buffer has space for `self.len()` entries, so the entry is in
+            //         bounds, and `Plain` types can be copied byte-wise
+            unsafe {
+                let entry = entries.as_mut_ptr().add(offset);
+                ptr::write_unaligned(
+                    entry.add(mem::offset_of!(Entry<K, V>, key)).cast::<K>(),
+                    *key,
+                );
+                ptr::write_unaligned(
+                    entry.add(mem::offset_of!(Entry<K, V>, value)).cast::<V>(),
+                    *value,
+                );
+            }
+
+            // insert the index of the entry in the first free slot (linear probing)
+            let hash = utils::make_hash::<K, K, B>(self.build_hasher(), key);
+            let mut slot = utils::adjust_hash_to(hash, capacity);
+            while super::read_u32(indices, slot * 4) != super::EMPTY {
+                slot = (slot + 1) % capacity;
+            }
+
+            let index = u32::try_from(index).map_err(|_| ArchiveError::LayoutMismatch)?;
+            indices[slot * 4..slot * 4 + 4].copy_from_slice(&index.to_le_bytes());
+        }
+
+        Header {
+            capacity,
+            len: self.len(),
+            fingerprint: super::fingerprint(self.build_hasher()),
+            seed: self.build_hasher().seed(),
+        }
+        .write::<K, V>(header)?;
+
+        let checksum = super::checksum(buffer);
+        buffer[super::CHECKSUM_OFFSET..super::HEADER_SIZE].copy_from_slice(&checksum.to_le_bytes());
+
+        Ok(size)
+    }
+}
@@ -0,0 +1,1038 @@
+use core::borrow::{Borrow, BorrowMut};
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::mem::{self, MaybeUninit};
+use core::ops::{Bound, Deref, DerefMut, Range, RangeBounds};
+use core::{fmt, ptr, slice};
+
+use crate::array_vec::{Drain, IntoIter};
+use crate::errors::{CapacityError, InsertError, UnavailableMutError};
+use crate::ext::{TryExtend, TryFromIterator};
+use crate::invariant;
+use crate::raw::TableIndex;
+use crate::utils::{self, ArrayExt};
+
+/// A vector with a fixed capacity of `N` elements, which are stored inline.
+///
+/// The initialized elements are stored contiguously at the start of the
+/// array, so the vector dereferences to a slice and all slice methods can be
+/// used on it.
+///
+/// Unlike an array, the vector does not implement [`Copy`], because it has to
+/// drop its initialized elements and a type that implements [`Drop`] can not
+/// be [`Copy`]. [`CopyArrayVec`] is a vector of [`Copy`] elements, which is
+/// [`Copy`] itself and dereferences to an [`ArrayVec`].
+///
+/// [`CopyArrayVec`]: crate::array_vec::CopyArrayVec
+///
+/// # Examples
+///
+/// ```
+/// use array_map::ArrayVec;
+///
+/// let mut vec: ArrayVec<u32, 4> = ArrayVec::new();
+/// vec.try_push(1)?;
+/// vec.try_push(2)?;
+///
+/// assert_eq!(vec.len(), 2);
+/// assert_eq!(vec[0], 1);
+/// assert_eq!(vec.pop(), Some(2));
+///
+/// vec.try_extend_from_slice(&[3, 4, 5])?;
+/// assert_eq!(vec.as_slice(), &[1, 3, 4, 5]);
+/// assert!(vec.try_push(6).is_err());
+/// # Ok::<_, array_map::CapacityError>(())
+/// ```
+// NOTE: the layout must be the same as the one of `CopyArrayVec`
+#[repr(C)]
+pub struct ArrayVec<T, const N: usize> {
+    pub(crate) data: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> ArrayVec<T, N> {
+    /// Creates an empty vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::ArrayVec;
+    ///
+    /// let vec: ArrayVec<u32, 4> = ArrayVec::new();
+    /// assert!(vec.is_empty());
+    /// assert_eq!(vec.capacity(), 4);
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            data: utils::uninit_array(),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of elements in the vector.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the vector is empty, `false` if it is not empty.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the vector is full, so no more elements can be added.
+    #[must_use]
+    pub const fn is_full(&self) -> bool {
+        self.len() == self.capacity()
+    }
+
+    /// Returns the maximum number of elements that can be stored in the vector.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of elements that can still be added to the vector.
+    #[must_use]
+    pub const fn remaining_capacity(&self) -> usize {
+        self.capacity() - self.len()
+    }
+
+    /// Returns a slice of all elements in the vector.
+    #[must_use]
+    pub const fn as_slice(&self) -> &[T] {
+        // SAFETY: the first self.len elements are initialized
+        unsafe { slice::from_raw_parts(self.as_ptr(), self.len) }
+    }
+
+    /// Returns a mutable slice of all elements in the vector.
+    #[must_use]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: the first self.len elements are initialized
+        unsafe { slice::from_raw_parts_mut(self.as_mut_ptr(), self.len) }
+    }
+
+    /// Returns a raw pointer to the buffer of the vector.
+    #[must_use]
+    pub const fn as_ptr(&self) -> *const T {
+        self.data.as_ptr().cast()
+    }
+
+    /// Returns a raw mutable pointer to the buffer of the vector.
+    #[must_use]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.data.as_mut_ptr().cast()
+    }
+
+    /// Appends an element to the end of the vector.
+    ///
+    /// # Errors
+    ///
+    /// If the vector is full, an [`InsertError`] is returned, which contains
+    /// the element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::ArrayVec;
+    ///
+    /// let mut vec: ArrayVec<u32, 1> = ArrayVec::new();
+    /// assert!(vec.try_push(1).is_ok());
+    /// assert_eq!(vec.try_push(2).unwrap_err().into_element(), 2);
+    /// assert_eq!(vec.as_slice(), &[1]);
+    /// ```
+    pub fn try_push(&mut self, value: T) -> Result<(), InsertError<T>> {
+        if self.is_full() {
+            return Err(InsertError::new(
+                CapacityError::new(N, self.len() + 1),
+                value,
+            ));
+        }
+
+        unsafe {
+            invariant!(self.len < self.capacity());
+            self.data.get_unchecked_mut(self.len).write(value);
+        }
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Appends an element to the end of the vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is full. Use [`ArrayVec::try_push`] to handle this
+    /// case.
+    #[cfg(feature = "panic")]
+    #[track_caller]
+    pub fn push(&mut self, value: T) {
+        self.try_push(value)
+            .unwrap_or_else(|_| utils::capacity_overflow(N));
+    }
+
+    /// Removes the last element from the vector and returns it.
+    ///
+    /// If the vector is empty, `None` is returned.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.len -= 1;
+        // SAFETY: the data structure is based on the assumption that everything up to
+        //         self.len() - 1 is initialized
+        //         and for the length the following must be true:
+        //         self.len() <= self.capacity()
+        //         with the previous statement self.len has been decremented
+        //         => self.len() < self.capacity()
+        //         the element is no longer part of the vector, so it is only read once
+        let value = unsafe {
+            invariant!(self.len < self.data.len());
+            self.data.get_unchecked(self.len).assume_init_read()
+        };
+
+        Some(value)
+    }
+
+    /// Inserts an element at the `index`, shifting all elements after it to
+    /// the right.
+    ///
+    /// # Errors
+    ///
+    /// If the vector is full, an [`InsertError`] is returned, which contains
+    /// the element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::ext::IteratorExt;
+    /// use array_map::ArrayVec;
+    ///
+    /// let mut vec: ArrayVec<u32, 3> = [1, 3].into_iter().try_collect()?;
+    /// vec.try_insert(1, 2)?;
+    /// assert_eq!(vec.as_slice(), &[1, 2, 3]);
+    /// assert!(vec.try_insert(0, 0).is_err());
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// O(n)
+    #[track_caller]
+    pub fn try_insert(&mut self, index: usize, value: T) -> Result<(), InsertError<T>> {
+        let len = self.len();
+        assert!(
+            index <= len,
+            "insertion index (is {index}) should be <= len (is {len})"
+        );
+
+        if self.is_full() {
+            return Err(InsertError::new(CapacityError::new(N, len + 1), value));
+        }
+
+        // SAFETY: there is space for one more element and the index is at most len
+        unsafe {
+            let ptr = self.as_mut_ptr().add(index);
+            // shift the following elements to the right, to make space for the new one
+            ptr::copy(ptr, ptr.add(1), len - index);
+            ptr.write(value);
+        }
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Inserts an element at the `index`, shifting all elements after it to
+    /// the right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len` or if the vector is full. Use
+    /// [`ArrayVec::try_insert`] to handle the latter case.
+    #[cfg(feature = "panic")]
+    #[track_caller]
+    pub fn insert(&mut self, index: usize, value: T) {
+        self.try_insert(index, value)
+            .unwrap_or_else(|_| utils::capacity_overflow(N));
+    }
+
+    /// Removes the element at the `index` and returns it, shifting all
+    /// elements after it to the left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::ext::IteratorExt;
+    /// use array_map::ArrayVec;
+    ///
+    /// let mut vec: ArrayVec<u32, 3> = [1, 2, 3].into_iter().try_collect()?;
+    /// assert_eq!(vec.remove(0), 1);
+    /// assert_eq!(vec.as_slice(), &[2, 3]);
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// O(n)
+    #[track_caller]
+    pub fn remove(&mut self, index: usize) -> T {
+        let len = self.len();
+        assert!(
+            index < len,
+            "removal index (is {index}) should be < len (is {len})"
+        );
+
+        // SAFETY: the index has been checked above
+        unsafe { self.remove_unchecked(index) }
+    }
+
+    /// Removes the element at the `index` and returns it, shifting all
+    /// elements after it to the left.
+    ///
+    /// # Safety
+    ///
+    /// This method assumes that the index is valid (`index < self.len()`).
+    ///
+    /// # Complexity
+    ///
+    /// This completes in `O(n)` time.
+    pub unsafe fn remove_unchecked(&mut self, index: usize) -> T {
+        invariant!(index < self.len());
+
+        let ptr = self.as_mut_ptr().add(index);
+        let value = ptr.read();
+        // shift the following elements to the left, which overwrites the read element
+        ptr::copy(ptr.add(1), ptr, self.len - index - 1);
+        self.len -= 1;
+
+        value
+    }
+
+    /// Removes the element at the `index` and returns it, replacing it with
+    /// the last element.
+    ///
+    /// This does not preserve the order of the elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::ext::IteratorExt;
+    /// use array_map::ArrayVec;
+    ///
+    /// let mut vec: ArrayVec<u32, 3> = [1, 2, 3].into_iter().try_collect()?;
+    /// assert_eq!(vec.swap_remove(0), 1);
+    /// assert_eq!(vec.as_slice(), &[3, 2]);
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// O(1)
+    #[track_caller]
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        let len = self.len();
+        assert!(
+            index < len,
+            "swap_remove index (is {index}) should be < len (is {len})"
+        );
+
+        // SAFETY: the index has been checked above, so the vector is not empty
+        unsafe {
+            let data = self.as_mut_ptr();
+            let value = data.add(index).read();
+            // the last element is moved into the gap, which does nothing for the last index
+            ptr::copy(data.add(len - 1), data.add(index), 1);
+            self.len -= 1;
+
+            value
+        }
+    }
+
+    /// Shortens the vector, keeping the first `len` elements and dropping the
+    /// rest.
+    ///
+    /// If `len` is greater than the length of the vector, this does nothing.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len() {
+            return;
+        }
+
+        let elements: *mut [T] = ptr::slice_from_raw_parts_mut(
+            // SAFETY: len is less than the length of the vector
+            unsafe { self.as_mut_ptr().add(len) },
+            self.len - len,
+        );
+        // the length is reset first, so the elements are not dropped twice if a
+        // destructor panics
+        self.len = len;
+
+        // SAFETY: the elements were initialized and are no longer part of the vector
+        unsafe { ptr::drop_in_place(elements) };
+    }
+
+    /// Removes all elements from the vector.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Retains only the elements for which the predicate returns `true`.
+    ///
+    /// The elements are visited in their order and the order of the retained
+    /// elements is preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::ext::IteratorExt;
+    /// use array_map::ArrayVec;
+    ///
+    /// let mut vec: ArrayVec<u32, 5> = (1..=5).try_collect()?;
+    /// vec.retain(|value| value % 2 == 1);
+    /// assert_eq!(vec.as_slice(), &[1, 3, 5]);
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// O(n)
+    pub fn retain(&mut self, mut f: impl FnMut(&T) -> bool) {
+        self.retain_mut(|value| f(value));
+    }
+
+    /// Retains only the elements for which the predicate returns `true`,
+    /// passing a mutable reference to each element.
+    ///
+    /// See [`ArrayVec::retain`] for more.
+    pub fn retain_mut(&mut self, mut f: impl FnMut(&mut T) -> bool) {
+        /// Moves the elements that have not been visited yet behind the
+        /// retained ones, even if the predicate or a destructor panics.
+        struct Guard<'a, T, const N: usize> {
+            vec: &'a mut ArrayVec<T, N>,
+            len: usize,
+            read: usize,
+            write: usize,
+        }
+
+        impl<'a, T, const N: usize> Drop for Guard<'a, T, N> {
+            fn drop(&mut self) {
+                let data = self.vec.as_mut_ptr();
+
+                // SAFETY: the elements in read..len are initialized and write <= read
+                unsafe {
+                    ptr::copy(
+                        data.add(self.read),
+                        data.add(self.write),
+                        self.len - self.read,
+                    );
+                    self.vec.set_len(self.write + self.len - self.read);
+                }
+            }
+        }
+
+        let len = self.len();
+        // the elements are managed by the guard until it is dropped
+        self.len = 0;
+        let mut guard = Guard {
+            vec: self,
+            len,
+            read: 0,
+            write: 0,
+        };
+
+        while guard.read < guard.len {
+            // SAFETY: the element at read is initialized and has not been moved yet
+            let current = unsafe { guard.vec.as_mut_ptr().add(guard.read) };
+            let keep = f(unsafe { &mut *current });
+            guard.read += 1;
+
+            if keep {
+                if guard.read - 1 != guard.write {
+                    // SAFETY: write < read, so the retained element is moved into a gap
+                    unsafe {
+                        ptr::copy_nonoverlapping(
+                            current,
+                            guard.vec.as_mut_ptr().add(guard.write),
+                            1,
+                        );
+                    }
+                }
+
+                guard.write += 1;
+            } else {
+                // SAFETY: the element is no longer part of the vector
+                unsafe { ptr::drop_in_place(current) };
+            }
+        }
+    }
+
+    /// Removes the elements in the range from the vector and returns them as
+    /// an iterator.
+    ///
+    /// The elements following the range are shifted to the left, once the
+    /// iterator is dropped. Elements that have not been yielded by then are
+    /// dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than the end or if the end
+    /// is greater than the length of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::ext::IteratorExt;
+    /// use array_map::ArrayVec;
+    ///
+    /// let mut vec: ArrayVec<u32, 5> = (1..=5).try_collect()?;
+    /// let drained: ArrayVec<u32, 2> = vec.drain(1..3).try_collect()?;
+    ///
+    /// assert_eq!(drained.as_slice(), &[2, 3]);
+    /// assert_eq!(vec.as_slice(), &[1, 4, 5]);
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    #[track_caller]
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T, N> {
+        let len = self.len();
+
+        let start = match range.start_bound() {
+            Bound::Included(index) => *index,
+            Bound::Excluded(index) => index
+                .checked_add(1)
+                .expect("attempted to index slice from after maximum usize"),
+            Bound::Unbounded => 0,
+        };
+
+        let end = match range.end_bound() {
+            Bound::Included(index) => index
+                .checked_add(1)
+                .expect("attempted to index slice up to maximum usize"),
+            Bound::Excluded(index) => *index,
+            Bound::Unbounded => len,
+        };
+
+        assert!(
+            start <= end,
+            "slice index starts at {start} but ends at {end}"
+        );
+        assert!(
+            end <= len,
+            "range end index {end} out of range for slice of length {len}"
+        );
+
+        Drain::new(self, start..end)
+    }
+
+    /// Clones and appends all elements of the slice to the vector.
+    ///
+    /// # Errors
+    ///
+    /// If the elements do not fit into the vector, a [`CapacityError`] is
+    /// returned and the vector is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::ArrayVec;
+    ///
+    /// let mut vec: ArrayVec<u32, 4> = ArrayVec::new();
+    /// vec.try_extend_from_slice(&[1, 2, 3])?;
+    /// assert!(vec.try_extend_from_slice(&[4, 5]).is_err());
+    /// assert_eq!(vec.as_slice(), &[1, 2, 3]);
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    pub fn try_extend_from_slice(&mut self, other: &[T]) -> Result<(), CapacityError>
+    where
+        T: Clone,
+    {
+        if other.len() > self.remaining_capacity() {
+            return Err(CapacityError::new(N, self.len() + other.len()));
+        }
+
+        for value in other {
+            // NOTE: this can not fail, because the capacity has been checked above
+            let _ = self.try_push(value.clone());
+        }
+
+        Ok(())
+    }
+
+    /// Clones and appends all elements of the slice to the vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the elements do not fit into the vector. Use
+    /// [`ArrayVec::try_extend_from_slice`] to handle this case.
+    #[cfg(feature = "panic")]
+    #[track_caller]
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        self.try_extend_from_slice(other)
+            .unwrap_or_else(|_| utils::capacity_overflow(N));
+    }
+
+    /// Sets the length of the vector without touching the underlying data.
+    ///
+    /// # Safety
+    ///
+    /// All elements before `len` must be initialized and `len` must not exceed
+    /// the capacity.
+    pub unsafe fn set_len(&mut self, len: usize) {
+        invariant!(len <= self.capacity());
+        self.len = len;
+    }
+
+    /// Moves the elements in the range into a new vector, shifting all
+    /// following elements to the left.
+    ///
+    /// # Safety
+    ///
+    /// This method assumes that the range is valid
+    /// (`range.start <= range.end <= self.len()`) and that the removed
+    /// elements fit into the new vector (`range.end - range.start <= M`).
+    ///
+    /// # Complexity
+    ///
+    /// This completes in `O(n)` time.
+    pub(crate) unsafe fn remove_range<const M: usize>(
+        &mut self,
+        range: Range<usize>,
+    ) -> ArrayVec<T, M> {
+        invariant!(range.start <= range.end);
+        invariant!(range.end <= self.len());
+
+        let count = range.end - range.start;
+        invariant!(count <= M);
+
+        let mut removed = ArrayVec::<T, M>::new();
+        let ptr = self.as_mut_ptr();
+        ptr::copy_nonoverlapping(ptr.add(range.start), removed.as_mut_ptr(), count);
+        removed.len = count;
+        // shift the following elements to the left, which overwrites the moved elements
+        ptr::copy(
+            ptr.add(range.end),
+            ptr.add(range.start),
+            self.len - range.end,
+        );
+        self.len -= count;
+
+        removed
+    }
+
+    pub(crate) fn get_each_mut_option<const M: usize>(
+        &mut self,
+        indices: [Option<TableIndex<N>>; M],
+    ) -> [Result<&mut T, UnavailableMutError>; M] {
+        let len = self.len();
+        let data = self.as_mut_ptr();
+        let mut borrowed: [Option<usize>; N] = utils::none_array();
+
+        indices.enumerate().map(|(position, index)| {
+            let index = index.ok_or(UnavailableMutError::Absent)?.index();
+
+            if index >= len {
+                return Err(UnavailableMutError::Absent);
+            }
+
+            unsafe {
+                invariant!(index < borrowed.len());
+
+                if let Some(position) = borrowed.get_unchecked(index) {
+                    Err(UnavailableMutError::Duplicate(*position))
+                } else {
+                    *borrowed.get_unchecked_mut(index) = Some(position);
+                    // SAFETY: the element is initialized and it is only borrowed once
+                    Ok(&mut *data.add(index))
+                }
+            }
+        })
+    }
+
+    /// Removes all elements from the vector and returns them in a new vector.
+    #[must_use]
+    pub(crate) fn take(&mut self) -> Self {
+        mem::take(self)
+    }
+}
+
+impl<T, const N: usize> Drop for ArrayVec<T, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, const N: usize> Deref for ArrayVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> DerefMut for ArrayVec<T, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, const N: usize> AsRef<[T]> for ArrayVec<T, N> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> AsMut<[T]> for ArrayVec<T, N> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, const N: usize> Borrow<[T]> for ArrayVec<T, N> {
+    fn borrow(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> BorrowMut<[T]> for ArrayVec<T, N> {
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for ArrayVec<T, N> {
+    fn clone(&self) -> Self {
+        let mut result = Self::new();
+
+        for value in self.as_slice() {
+            // NOTE: this can not fail, because both vectors have the same capacity
+            let _ = result.try_push(value.clone());
+        }
+
+        result
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for ArrayVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for ArrayVec<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for ArrayVec<T, N> {}
+
+impl<T: PartialOrd, const N: usize> PartialOrd for ArrayVec<T, N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: Ord, const N: usize> Ord for ArrayVec<T, N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<T: Hash, const N: usize> Hash for ArrayVec<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+impl<T, const N: usize> TryFromIterator<T> for ArrayVec<T, N> {
+    type Error = CapacityError;
+
+    fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, Self::Error> {
+        let mut vec = Self::new();
+        vec.try_extend(iter)?;
+
+        Ok(vec)
+    }
+}
+
+/// The elements are appended in the order of the iterator. If the vector runs
+/// out of capacity, the elements that have been appended before are kept.
+impl<T, const N: usize> TryExtend<T> for ArrayVec<T, N> {
+    type Error = CapacityError;
+
+    fn try_extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), Self::Error> {
+        for item in iter {
+            self.try_push(item)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T, const N: usize> IntoIterator for ArrayVec<T, N> {
+    type IntoIter = IntoIter<T, N>;
+    type Item = T;
+
+    fn into_iter(mut self) -> Self::IntoIter {
+        let end = self.len;
+        // the elements are moved into the iterator, which is responsible for dropping
+        // them
+        self.len = 0;
+
+        IntoIter::new(mem::replace(&mut self.data, utils::uninit_array()), end)
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a ArrayVec<T, N> {
+    type IntoIter = slice::Iter<'a, T>;
+    type Item = &'a T;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_slice().iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut ArrayVec<T, N> {
+    type IntoIter = slice::IterMut<'a, T>;
+    type Item = &'a mut T;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_mut_slice().iter_mut()
+    }
+}
+
+impl<T, const N: usize> Default for ArrayVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    use crate::ext::IteratorExt;
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    struct Counted<'a>(&'a Cell<usize>);
+
+    impl Drop for Counted<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[test]
+    fn test_default() {
+        struct DoesNotImplementDefault;
+
+        let vec: ArrayVec<DoesNotImplementDefault, 5> = ArrayVec::default();
+        assert_eq!(vec.is_empty(), true);
+    }
+
+    #[test]
+    fn test_push() {
+        const N: usize = 21;
+
+        let mut vec: ArrayVec<_, N> = ArrayVec::new();
+
+        for value in 0..N {
+            vec.try_push(value).unwrap();
+        }
+
+        assert_eq!(vec.len(), N);
+        assert_eq!(vec.is_full(), true);
+        assert_eq!(
+            vec.try_push(N),
+            Err(InsertError::new(CapacityError::new(N, N + 1), N))
+        );
+
+        for (index, value) in vec.iter().enumerate() {
+            assert_eq!(*value, index);
+        }
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut vec: ArrayVec<_, 5> = (0..5).try_collect().unwrap();
+
+        assert_eq!(vec.remove(1), 1);
+        assert_eq!(vec.as_slice(), &[0, 2, 3, 4]);
+
+        assert_eq!(vec.swap_remove(0), 0);
+        assert_eq!(vec.as_slice(), &[4, 2, 3]);
+
+        assert_eq!(vec.remove(2), 3);
+        assert_eq!(vec.as_slice(), &[4, 2]);
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut vec: ArrayVec<_, 4> = ArrayVec::new();
+
+        vec.try_insert(0, 2).unwrap();
+        vec.try_insert(0, 0).unwrap();
+        vec.try_insert(1, 1).unwrap();
+        vec.try_insert(3, 3).unwrap();
+
+        assert_eq!(vec.as_slice(), &[0, 1, 2, 3]);
+        assert_eq!(
+            vec.try_insert(2, 4).map_err(InsertError::into_element),
+            Err(4)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "attempted to index slice from after maximum usize")]
+    fn test_drain_excluded_start_overflow() {
+        let mut vec: ArrayVec<u32, 2> = ArrayVec::new();
+        vec.drain((Bound::Excluded(usize::MAX), Bound::Unbounded));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempted to index slice up to maximum usize")]
+    fn test_drain_included_end_overflow() {
+        let mut vec: ArrayVec<u32, 2> = ArrayVec::new();
+        vec.drain(..=usize::MAX);
+    }
+
+    #[test]
+    fn test_remove_range() {
+        let mut vec: ArrayVec<_, 8> = (0..6).try_collect().unwrap();
+
+        let removed: ArrayVec<_, 3> = unsafe { vec.remove_range(1..4) };
+
+        assert_eq!(removed.as_slice(), &[1, 2, 3]);
+        assert_eq!(vec.as_slice(), &[0, 4, 5]);
+    }
+
+    #[test]
+    fn test_retain_drops_rejected() {
+        let dropped = Cell::new(0);
+        let mut vec: ArrayVec<_, 6> = (0..6)
+            .map(|value| (value, Counted(&dropped)))
+            .try_collect()
+            .unwrap();
+
+        vec.retain(|(value, _)| value % 3 != 0);
+
+        assert_eq!(dropped.get(), 2);
+        assert_eq!(
+            vec.iter().map(|(value, _)| *value).try_collect(),
+            Ok([1, 2, 4, 5])
+        );
+    }
+
+    #[test]
+    fn test_truncate() {
+        let dropped = Cell::new(0);
+        let mut vec: ArrayVec<_, 4> = (0..4).map(|_| Counted(&dropped)).try_collect().unwrap();
+
+        vec.truncate(5);
+        assert_eq!(dropped.get(), 0);
+
+        vec.truncate(1);
+        assert_eq!(dropped.get(), 3);
+        assert_eq!(vec.len(), 1);
+
+        mem::drop(vec);
+        assert_eq!(dropped.get(), 4);
+    }
+
+    #[test]
+    fn test_drain() {
+        let mut vec: ArrayVec<_, 8> = (0..6).try_collect().unwrap();
+
+        let mut drain = vec.drain(1..5);
+        assert_eq!(drain.len(), 4);
+        assert_eq!(drain.next(), Some(1));
+        assert_eq!(drain.next_back(), Some(4));
+        assert_eq!(drain.as_slice(), &[2, 3]);
+        mem::drop(drain);
+
+        assert_eq!(vec.as_slice(), &[0, 5]);
+
+        assert_eq!(vec.drain(..).try_collect(), Ok([0, 5]));
+        assert_eq!(vec.is_empty(), true);
+    }
+
+    #[test]
+    fn test_drain_drops_remaining() {
+        let dropped = Cell::new(0);
+        let mut vec: ArrayVec<_, 5> = (0..5)
+            .map(|value| (value, Counted(&dropped)))
+            .try_collect()
+            .unwrap();
+
+        let mut drain = vec.drain(1..=3);
+        mem::drop(drain.next());
+        mem::drop(drain);
+
+        assert_eq!(dropped.get(), 3);
+        assert_eq!(
+            vec.iter().map(|(value, _)| *value).try_collect(),
+            Ok([0, 4])
+        );
+    }
+
+    #[test]
+    fn test_extend() {
+        let mut vec: ArrayVec<_, 4> = ArrayVec::new();
+
+        vec.try_extend([1, 2]).unwrap();
+        vec.try_extend_from_slice(&[3]).unwrap();
+        assert_eq!(
+            vec.try_extend_from_slice(&[4, 5]),
+            Err(CapacityError::new(4, 5))
+        );
+        assert_eq!(vec.as_slice(), &[1, 2, 3]);
+
+        assert_eq!(vec.try_extend([4, 5]), Err(CapacityError::new(4, 5)));
+        assert_eq!(vec.as_slice(), &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_into_iter_drops_remaining() {
+        let dropped = Cell::new(0);
+        let vec: ArrayVec<_, 4> = (0..3).map(|_| Counted(&dropped)).try_collect().unwrap();
+
+        let mut iter = vec.into_iter();
+        mem::drop(iter.next_back());
+        assert_eq!(dropped.get(), 1);
+        assert_eq!(iter.len(), 2);
+
+        mem::drop(iter);
+        assert_eq!(dropped.get(), 3);
+    }
+
+    #[test]
+    fn test_into_iter_nth() {
+        let vec: ArrayVec<_, 8> = (0..6).try_collect().unwrap();
+        let mut iter = vec.into_iter();
+
+        assert_eq!(iter.nth(1), Some(1));
+        assert_eq!(iter.nth_back(1), Some(4));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.nth(2), None);
+        assert_eq!(iter.len(), 0);
+    }
+}
@@ -0,0 +1,227 @@
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ops::{Deref, DerefMut};
+use core::{fmt, ptr, slice};
+
+use crate::array_vec::{ArrayVec, IntoIter};
+use crate::errors::CapacityError;
+use crate::ext::{TryExtend, TryFromIterator};
+use crate::utils;
+
+/// An [`ArrayVec`] of [`Copy`] elements, which is [`Copy`] itself.
+///
+/// The elements never have to be dropped, so unlike an [`ArrayVec`] this
+/// vector does not implement [`Drop`]. It dereferences to an [`ArrayVec`] and
+/// therefore has the same API.
+///
+/// It can be used as the storage of an [`IndexMap`](crate::IndexMap) or an
+/// [`IndexSet`](crate::set::IndexSet) to make them [`Copy`] as well.
+///
+/// # Examples
+///
+/// ```
+/// use array_map::array_vec::CopyArrayVec;
+///
+/// let mut vec: CopyArrayVec<u32, 4> = CopyArrayVec::new();
+/// vec.try_push(1)?;
+///
+/// let copy = vec;
+/// vec.try_push(2)?;
+///
+/// assert_eq!(copy.as_slice(), &[1]);
+/// assert_eq!(vec.as_slice(), &[1, 2]);
+/// # Ok::<_, array_map::CapacityError>(())
+/// ```
+// NOTE: the layout must be the same as the one of `ArrayVec`
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct CopyArrayVec<T: Copy, const N: usize> {
+    data: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T: Copy, const N: usize> CopyArrayVec<T, N> {
+    /// Creates an empty vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::array_vec::CopyArrayVec;
+    ///
+    /// let vec: CopyArrayVec<u32, 4> = CopyArrayVec::new();
+    /// assert!(vec.is_empty());
+    /// assert_eq!(vec.capacity(), 4);
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            data: utils::uninit_array(),
+            len: 0,
+        }
+    }
+}
+
+impl<T: Copy, const N: usize> Deref for CopyArrayVec<T, N> {
+    type Target = ArrayVec<T, N>;
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: both types have the same layout and the elements do not have to be
+        //         dropped, so it does not matter whether the vector implements `Drop`
+        unsafe { &*ptr::from_ref(self).cast::<ArrayVec<T, N>>() }
+    }
+}
+
+impl<T: Copy, const N: usize> DerefMut for CopyArrayVec<T, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: see `deref`
+        unsafe { &mut *ptr::from_mut(self).cast::<ArrayVec<T, N>>() }
+    }
+}
+
+impl<T: Copy, const N: usize> AsRef<[T]> for CopyArrayVec<T, N> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T: Copy, const N: usize> AsMut<[T]> for CopyArrayVec<T, N> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: Copy, const N: usize> From<ArrayVec<T, N>> for CopyArrayVec<T, N> {
+    fn from(value: ArrayVec<T, N>) -> Self {
+        let value = ManuallyDrop::new(value);
+
+        // SAFETY: both types have the same layout and the vector is not dropped
+        unsafe { ptr::read(ptr::from_ref(&*value).cast::<Self>()) }
+    }
+}
+
+impl<T: Copy, const N: usize> From<CopyArrayVec<T, N>> for ArrayVec<T, N> {
+    fn from(value: CopyArrayVec<T, N>) -> Self {
+        // SAFETY: both types have the same layout and the elements are `Copy`
+        unsafe { ptr::read(ptr::from_ref(&value).cast::<Self>()) }
+    }
+}
+
+impl<T: Copy + fmt::Debug, const N: usize> fmt::Debug for CopyArrayVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+impl<T: Copy + PartialEq, const N: usize> PartialEq for CopyArrayVec<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Copy + Eq, const N: usize> Eq for CopyArrayVec<T, N> {}
+
+impl<T: Copy + PartialOrd, const N: usize> PartialOrd for CopyArrayVec<T, N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: Copy + Ord, const N: usize> Ord for CopyArrayVec<T, N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<T: Copy + Hash, const N: usize> Hash for CopyArrayVec<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+impl<T: Copy, const N: usize> TryFromIterator<T> for CopyArrayVec<T, N> {
+    type Error = CapacityError;
+
+    fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, Self::Error> {
+        ArrayVec::try_from_iter(iter).map(Self::from)
+    }
+}
+
+impl<T: Copy, const N: usize> TryExtend<T> for CopyArrayVec<T, N> {
+    type Error = CapacityError;
+
+    fn try_extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), Self::Error> {
+        (**self).try_extend(iter)
+    }
+}
+
+impl<T: Copy, const N: usize> IntoIterator for CopyArrayVec<T, N> {
+    type IntoIter = IntoIter<T, N>;
+    type Item = T;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ArrayVec::from(self).into_iter()
+    }
+}
+
+impl<'a, T: Copy, const N: usize> IntoIterator for &'a CopyArrayVec<T, N> {
+    type IntoIter = slice::Iter<'a, T>;
+    type Item = &'a T;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_slice().iter()
+    }
+}
+
+impl<'a, T: Copy, const N: usize> IntoIterator for &'a mut CopyArrayVec<T, N> {
+    type IntoIter = slice::IterMut<'a, T>;
+    type Item = &'a mut T;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_mut_slice().iter_mut()
+    }
+}
+
+impl<T: Copy, const N: usize> Default for CopyArrayVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::ext::IteratorExt;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_copies_are_independent() {
+        let mut vec: CopyArrayVec<u32, 4> = [1, 2, 3].into_iter().try_collect().unwrap();
+        let copy = vec;
+
+        vec.remove(0);
+        vec.try_push(4).unwrap();
+
+        assert_eq!(vec.as_slice(), &[2, 3, 4]);
+        assert_eq!(copy.as_slice(), &[1, 2, 3]);
+        assert_eq!(
+            ArrayVec::from(copy).into_iter().try_collect(),
+            Ok([1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn test_drain_and_retain() {
+        let mut vec: CopyArrayVec<u32, 6> = (0..6).try_collect().unwrap();
+
+        assert_eq!(vec.drain(1..3).try_collect(), Ok([1, 2]));
+        vec.retain(|value| *value != 4);
+
+        assert_eq!(vec.as_slice(), &[0, 3, 5]);
+        assert_eq!(
+            vec,
+            CopyArrayVec::from(ArrayVec::try_from_iter([0, 3, 5]).unwrap())
+        );
+    }
+}
@@ -0,0 +1,124 @@
+use core::iter::FusedIterator;
+use core::ops::Range;
+use core::{fmt, ptr, slice};
+
+use crate::array_vec::ArrayVec;
+use crate::invariant;
+
+/// A draining iterator over a range of the elements of an [`ArrayVec`].
+///
+/// This struct is created by [`ArrayVec::drain`]. See its documentation for
+/// more.
+pub struct Drain<'a, T, const N: usize> {
+    /// The vector is truncated to the start of the drained range, while the
+    /// iterator exists.
+    vec: &'a mut ArrayVec<T, N>,
+    /// The elements in `start..end` have not been yielded yet.
+    start: usize,
+    end: usize,
+    /// The elements in `tail_start..tail_end` follow the drained range and are
+    /// moved back into the vector, once the iterator is dropped.
+    tail_start: usize,
+    tail_end: usize,
+}
+
+impl<'a, T, const N: usize> Drain<'a, T, N> {
+    pub(crate) fn new(vec: &'a mut ArrayVec<T, N>, range: Range<usize>) -> Self {
+        let tail_end = vec.len();
+        invariant!(range.start <= range.end);
+        invariant!(range.end <= tail_end);
+
+        // SAFETY: the elements starting at range.start are managed by the iterator
+        unsafe { vec.set_len(range.start) };
+
+        Self {
+            vec,
+            start: range.start,
+            end: range.end,
+            tail_start: range.end,
+            tail_end,
+        }
+    }
+
+    /// Returns the remaining elements as a slice.
+    #[must_use]
+    pub const fn as_slice(&self) -> &[T] {
+        // SAFETY: the elements in start..end are initialized
+        unsafe { slice::from_raw_parts(self.vec.as_ptr().add(self.start), self.end - self.start) }
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for Drain<'a, T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+
+        // SAFETY: the element at start is initialized and is no longer part of the
+        //         remaining elements after start has been incremented
+        let value = unsafe { self.vec.as_ptr().add(self.start).read() };
+        self.start += 1;
+
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T, const N: usize> DoubleEndedIterator for Drain<'a, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+
+        self.end -= 1;
+        // SAFETY: the element at end is initialized and is no longer part of the
+        //         remaining elements
+        let value = unsafe { self.vec.as_ptr().add(self.end).read() };
+
+        Some(value)
+    }
+}
+
+impl<'a, T, const N: usize> ExactSizeIterator for Drain<'a, T, N> {}
+
+impl<'a, T, const N: usize> FusedIterator for Drain<'a, T, N> {}
+
+impl<'a, T: fmt::Debug, const N: usize> fmt::Debug for Drain<'a, T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+impl<'a, T, const N: usize> Drop for Drain<'a, T, N> {
+    fn drop(&mut self) {
+        let remaining: *mut [T] = ptr::slice_from_raw_parts_mut(
+            // SAFETY: start is at most N
+            unsafe { self.vec.as_mut_ptr().add(self.start) },
+            self.end - self.start,
+        );
+        self.start = self.end;
+
+        // SAFETY: the remaining elements are initialized and will not be visited again,
+        //         if a destructor panics the tail is leaked, but not dropped twice
+        unsafe { ptr::drop_in_place(remaining) };
+
+        let len = self.vec.len();
+        let data = self.vec.as_mut_ptr();
+        // SAFETY: the tail is initialized and is moved directly behind the elements in
+        //         front of the drained range
+        unsafe {
+            ptr::copy(
+                data.add(self.tail_start),
+                data.add(len),
+                self.tail_end - self.tail_start,
+            );
+            self.vec.set_len(len + self.tail_end - self.tail_start);
+        }
+    }
+}
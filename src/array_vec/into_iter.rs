@@ -0,0 +1,164 @@
+use core::iter::FusedIterator;
+use core::mem::MaybeUninit;
+use core::{fmt, ptr, slice};
+
+use crate::array_vec::ArrayVec;
+use crate::ext::ToIter;
+use crate::invariant;
+
+/// An iterator that moves the elements out of an [`ArrayVec`].
+///
+/// This struct is created by the [`IntoIterator`] implementation of
+/// [`ArrayVec`].
+pub struct IntoIter<T, const N: usize> {
+    data: [MaybeUninit<T>; N],
+    /// The elements in `start..end` are initialized.
+    start: usize,
+    end: usize,
+}
+
+impl<T, const N: usize> IntoIter<T, N> {
+    /// Creates an iterator over the first `len` elements of the array, which
+    /// must be initialized.
+    pub(crate) const fn new(data: [MaybeUninit<T>; N], len: usize) -> Self {
+        Self {
+            data,
+            start: 0,
+            end: len,
+        }
+    }
+
+    /// Returns the remaining elements as a slice.
+    #[must_use]
+    pub const fn as_slice(&self) -> &[T] {
+        // SAFETY: the elements in start..end are initialized
+        unsafe {
+            slice::from_raw_parts(
+                self.data.as_ptr().add(self.start).cast(),
+                self.end - self.start,
+            )
+        }
+    }
+}
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+
+        // SAFETY: the element at start is initialized and is no longer part of the
+        //         remaining elements after start has been incremented
+        let value = unsafe {
+            invariant!(self.start < self.data.len());
+            self.data.get_unchecked(self.start).assume_init_read()
+        };
+        self.start += 1;
+
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let skipped = n.min(self.end - self.start);
+        let skipped_elements: *mut [T] = ptr::slice_from_raw_parts_mut(
+            // SAFETY: start is at most N
+            unsafe { self.data.as_mut_ptr().add(self.start).cast() },
+            skipped,
+        );
+        self.start += skipped;
+
+        // SAFETY: the skipped elements are initialized and will not be visited again
+        unsafe { ptr::drop_in_place(skipped_elements) };
+
+        self.next()
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+
+        self.end -= 1;
+        // SAFETY: the element at end is initialized and is no longer part of the
+        //         remaining elements
+        let value = unsafe {
+            invariant!(self.end < self.data.len());
+            self.data.get_unchecked(self.end).assume_init_read()
+        };
+
+        Some(value)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let skipped = n.min(self.end - self.start);
+        self.end -= skipped;
+        let skipped_elements: *mut [T] = ptr::slice_from_raw_parts_mut(
+            // SAFETY: end is at most N
+            unsafe { self.data.as_mut_ptr().add(self.end).cast() },
+            skipped,
+        );
+
+        // SAFETY: the skipped elements are initialized and will not be visited again
+        unsafe { ptr::drop_in_place(skipped_elements) };
+
+        self.next_back()
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {}
+
+impl<T, const N: usize> FusedIterator for IntoIter<T, N> {}
+
+impl<T, const N: usize> ToIter for IntoIter<T, N> {
+    type Item = T;
+    type Iter<'b>
+        = slice::Iter<'b, T>
+    where
+        Self::Item: 'b;
+
+    fn iter(&self) -> Self::Iter<'_> {
+        self.as_slice().iter()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for IntoIter<T, N> {
+    fn clone(&self) -> Self {
+        let mut vec = ArrayVec::<T, N>::new();
+
+        for value in self.as_slice() {
+            // NOTE: this can not fail, because there are at most N remaining elements
+            let _ = vec.try_push(value.clone());
+        }
+
+        vec.into_iter()
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for IntoIter<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+impl<T, const N: usize> Drop for IntoIter<T, N> {
+    fn drop(&mut self) {
+        let remaining: *mut [T] = ptr::slice_from_raw_parts_mut(
+            // SAFETY: start is at most N
+            unsafe { self.data.as_mut_ptr().add(self.start).cast() },
+            self.end - self.start,
+        );
+        self.start = self.end;
+
+        // SAFETY: the remaining elements are initialized and will not be visited again
+        unsafe { ptr::drop_in_place(remaining) };
+    }
+}
@@ -0,0 +1,11 @@
+mod array_vec;
+mod copy_array_vec;
+mod drain;
+mod into_iter;
+mod storage;
+
+pub use array_vec::*;
+pub use copy_array_vec::*;
+pub use drain::*;
+pub use into_iter::*;
+pub use storage::*;
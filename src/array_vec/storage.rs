@@ -0,0 +1,59 @@
+use crate::array_vec::{ArrayVec, CopyArrayVec};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// The vector in which an [`IndexMap`](crate::IndexMap) or an
+/// [`IndexSet`](crate::set::IndexSet) stores its entries.
+///
+/// It is implemented by [`ArrayVec`], which is used by default, and by
+/// [`CopyArrayVec`], which makes the collection [`Copy`] if its entries and
+/// its hasher are [`Copy`].
+///
+/// This trait is sealed and can not be implemented outside of this crate.
+pub trait VecStorage<T, const N: usize>: Default + private::Sealed {
+    /// Returns the vector the entries are stored in.
+    #[must_use]
+    fn as_array_vec(&self) -> &ArrayVec<T, N>;
+
+    /// Returns the vector the entries are stored in as a mutable reference.
+    #[must_use]
+    fn as_mut_array_vec(&mut self) -> &mut ArrayVec<T, N>;
+
+    /// Moves the entries into an [`ArrayVec`].
+    #[must_use]
+    fn into_array_vec(self) -> ArrayVec<T, N>;
+}
+
+impl<T, const N: usize> private::Sealed for ArrayVec<T, N> {}
+
+impl<T, const N: usize> VecStorage<T, N> for ArrayVec<T, N> {
+    fn as_array_vec(&self) -> &Self {
+        self
+    }
+
+    fn as_mut_array_vec(&mut self) -> &mut Self {
+        self
+    }
+
+    fn into_array_vec(self) -> Self {
+        self
+    }
+}
+
+impl<T: Copy, const N: usize> private::Sealed for CopyArrayVec<T, N> {}
+
+impl<T: Copy, const N: usize> VecStorage<T, N> for CopyArrayVec<T, N> {
+    fn as_array_vec(&self) -> &ArrayVec<T, N> {
+        self
+    }
+
+    fn as_mut_array_vec(&mut self) -> &mut ArrayVec<T, N> {
+        self
+    }
+
+    fn into_array_vec(self) -> ArrayVec<T, N> {
+        self.into()
+    }
+}
@@ -0,0 +1,79 @@
+use core::fmt;
+
+/// The error type for writing and reading archived maps.
+///
+/// See [`crate::archive`] for more details about the archived layout.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ArchiveError {
+    /// The buffer is smaller than the archived map.
+    BufferTooSmall { required: usize, actual: usize },
+    /// The buffer is not aligned for the entries of the map.
+    Misaligned { align: usize },
+    /// The buffer does not start with a supported archive header.
+    InvalidHeader,
+    /// The archive has been written for other key or value types or on a
+    /// platform with a different byte order.
+    LayoutMismatch,
+    /// The archive has been written for a map with a different capacity.
+    CapacityMismatch { expected: usize, actual: usize },
+    /// The hasher does not produce the hashes the archive has been written
+    /// with.
+    HasherMismatch,
+    /// The stored checksum does not match the contents of the archive.
+    ChecksumMismatch,
+    /// The archive passed the checksum, but its slots are inconsistent.
+    Corrupted,
+}
+
+impl fmt::Display for ArchiveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BufferTooSmall { required, actual } => write!(
+                f,
+                "the buffer has `{actual}` bytes, but the archive needs `{required}` bytes"
+            ),
+            Self::Misaligned { align } => {
+                write!(f, "the buffer must be aligned to `{align}` bytes")
+            }
+            Self::InvalidHeader => write!(f, "invalid archive header"),
+            Self::LayoutMismatch => write!(f, "the archive has an incompatible layout"),
+            Self::CapacityMismatch { expected, actual } => write!(
+                f,
+                "expected an archive with capacity `{expected}`, found capacity `{actual}`"
+            ),
+            Self::HasherMismatch => write!(f, "the archive has been written with another hasher"),
+            Self::ChecksumMismatch => write!(f, "the checksum of the archive does not match"),
+            Self::Corrupted => write!(f, "the archive is corrupted"),
+        }
+    }
+}
+
+impl core::error::Error for ArchiveError {}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+
+    use alloc::string::ToString;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_archive_error_display() {
+        assert_eq!(
+            ArchiveError::BufferTooSmall {
+                required: 128,
+                actual: 96
+            }
+            .to_string(),
+            "the buffer has `96` bytes, but the archive needs `128` bytes"
+        );
+        assert_eq!(
+            ArchiveError::CapacityMismatch {
+                expected: 7,
+                actual: 5
+            }
+            .to_string(),
+            "expected an archive with capacity `7`, found capacity `5`"
+        );
+    }
+}
@@ -1,14 +1,49 @@
 use core::fmt;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct CapacityError;
+/// The error returned when an element is inserted into a collection, which is
+/// already full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CapacityError {
+    capacity: usize,
+    attempted_len: usize,
+}
+
+impl CapacityError {
+    #[must_use]
+    pub(crate) const fn new(capacity: usize, attempted_len: usize) -> Self {
+        Self {
+            capacity,
+            attempted_len,
+        }
+    }
+
+    /// Returns the capacity of the collection that caused the error.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of elements the collection would have had after the
+    /// failed operation.
+    #[must_use]
+    pub const fn attempted_len(&self) -> usize {
+        self.attempted_len
+    }
+}
 
 impl fmt::Display for CapacityError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "not enough space")
+        write!(
+            f,
+            "not enough space for `{attempted_len}` elements in a collection with capacity `{capacity}`",
+            attempted_len = self.attempted_len,
+            capacity = self.capacity
+        )
     }
 }
 
+impl core::error::Error for CapacityError {}
+
 #[cfg(all(test, feature = "alloc"))]
 mod tests {
     use super::*;
@@ -18,6 +53,9 @@ mod tests {
 
     #[test]
     fn test_capacity_error_display() {
-        assert_eq!(CapacityError.to_string(), "not enough space");
+        assert_eq!(
+            CapacityError::new(2, 3).to_string(),
+            "not enough space for `3` elements in a collection with capacity `2`"
+        );
     }
 }
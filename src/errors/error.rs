@@ -0,0 +1,130 @@
+use core::fmt;
+
+use crate::errors::{
+    rescale, ArchiveError, CapacityError, InsertError, RescaleError, UnavailableMutError,
+};
+use crate::ext::CollectArrayError;
+use crate::map::IndexOutOfBoundsError;
+
+/// An error that combines all errors of this crate, so they can be
+/// propagated with `?` through a single error type.
+///
+/// # Examples
+///
+/// ```
+/// use array_map::{ArrayMap, Error};
+///
+/// fn build() -> Result<ArrayMap<u8, u8, 4>, Error> {
+///     let mut map: ArrayMap<u8, u8, 2> = ArrayMap::new();
+///     map.try_insert(1, 2)?;
+///
+///     Ok(map.try_rescale()?)
+/// }
+///
+/// assert_eq!(build()?.len(), 1);
+/// # Ok::<_, Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// See [`CapacityError`].
+    Capacity(CapacityError),
+    /// See [`RescaleError`].
+    Rescale {
+        required_size: usize,
+        old_capacity: usize,
+        new_capacity: usize,
+    },
+    /// See [`UnavailableMutError`].
+    UnavailableMut(UnavailableMutError),
+    /// See [`IndexOutOfBoundsError`].
+    IndexOutOfBounds(IndexOutOfBoundsError),
+    /// See [`CollectArrayError`].
+    CollectArray(CollectArrayError),
+    /// See [`ArchiveError`].
+    Archive(ArchiveError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Capacity(error) => fmt::Display::fmt(error, f),
+            Self::Rescale {
+                required_size,
+                old_capacity,
+                new_capacity,
+            } => rescale::fmt_rescale_error(*required_size, *old_capacity, *new_capacity, f),
+            Self::UnavailableMut(error) => fmt::Display::fmt(error, f),
+            Self::IndexOutOfBounds(error) => fmt::Display::fmt(error, f),
+            Self::CollectArray(error) => fmt::Display::fmt(error, f),
+            Self::Archive(error) => fmt::Display::fmt(error, f),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+impl From<CapacityError> for Error {
+    fn from(value: CapacityError) -> Self {
+        Self::Capacity(value)
+    }
+}
+
+impl<T> From<InsertError<T>> for Error {
+    fn from(value: InsertError<T>) -> Self {
+        Self::Capacity(value.into())
+    }
+}
+
+impl<const NEW_CAP: usize> From<RescaleError<NEW_CAP>> for Error {
+    fn from(value: RescaleError<NEW_CAP>) -> Self {
+        Self::Rescale {
+            required_size: value.required_size(),
+            old_capacity: value.old_capacity(),
+            new_capacity: NEW_CAP,
+        }
+    }
+}
+
+impl From<UnavailableMutError> for Error {
+    fn from(value: UnavailableMutError) -> Self {
+        Self::UnavailableMut(value)
+    }
+}
+
+impl From<IndexOutOfBoundsError> for Error {
+    fn from(value: IndexOutOfBoundsError) -> Self {
+        Self::IndexOutOfBounds(value)
+    }
+}
+
+impl From<CollectArrayError> for Error {
+    fn from(value: CollectArrayError) -> Self {
+        Self::CollectArray(value)
+    }
+}
+
+impl From<ArchiveError> for Error {
+    fn from(value: ArchiveError) -> Self {
+        Self::Archive(value)
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+
+    use alloc::string::ToString;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_error_display_matches_source() {
+        let rescale = RescaleError::<4>::new(5, 6);
+
+        assert_eq!(Error::from(rescale.clone()).to_string(), rescale.to_string());
+        assert_eq!(
+            Error::from(CapacityError::new(1, 2)).to_string(),
+            CapacityError::new(1, 2).to_string()
+        );
+    }
+}
@@ -0,0 +1,96 @@
+use core::fmt;
+
+use crate::errors::CapacityError;
+
+/// The error returned when an element is inserted into an
+/// [`ArrayVec`](crate::ArrayVec), which is already full.
+///
+/// The rejected element is returned inside the error, so it is not lost.
+///
+/// # Examples
+///
+/// ```
+/// use array_map::ArrayVec;
+///
+/// let mut vec: ArrayVec<&str, 1> = ArrayVec::new();
+/// vec.try_push("a")?;
+///
+/// let error = vec.try_push("b").unwrap_err();
+/// assert_eq!(error.capacity_error().capacity(), 1);
+/// assert_eq!(error.into_element(), "b");
+/// # Ok::<_, array_map::CapacityError>(())
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InsertError<T> {
+    error: CapacityError,
+    element: T,
+}
+
+impl<T> InsertError<T> {
+    #[must_use]
+    pub(crate) const fn new(error: CapacityError, element: T) -> Self {
+        Self { error, element }
+    }
+
+    /// Returns a reference to the element that could not be inserted.
+    #[must_use]
+    pub const fn element(&self) -> &T {
+        &self.element
+    }
+
+    /// Returns the element that could not be inserted.
+    #[must_use]
+    pub fn into_element(self) -> T {
+        self.element
+    }
+
+    /// Returns the [`CapacityError`] without the element.
+    #[must_use]
+    pub const fn capacity_error(&self) -> CapacityError {
+        self.error
+    }
+}
+
+impl<T> fmt::Debug for InsertError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InsertError")
+            .field("capacity", &self.error.capacity())
+            .field("attempted_len", &self.error.attempted_len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> fmt::Display for InsertError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.error, f)
+    }
+}
+
+impl<T> core::error::Error for InsertError<T> {}
+
+impl<T> From<InsertError<T>> for CapacityError {
+    fn from(value: InsertError<T>) -> Self {
+        value.error
+    }
+}
+
+#[cfg(all(test, feature = "ahash"))]
+mod tests {
+    use super::*;
+
+    use crate::Error;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_insert_error_conversions() {
+        let error = InsertError::new(CapacityError::new(2, 3), "value");
+
+        assert_eq!(error.element(), &"value");
+        assert_eq!(CapacityError::from(error), CapacityError::new(2, 3));
+        assert_eq!(
+            Error::from(error),
+            Error::Capacity(CapacityError::new(2, 3))
+        );
+        assert_eq!(error.into_element(), "value");
+    }
+}
@@ -1,7 +1,13 @@
+mod archive;
 mod capacity;
+mod error;
+mod insert;
 mod rescale;
 mod unavailable_mut;
 
+pub use archive::*;
 pub use capacity::*;
+pub use error::*;
+pub use insert::*;
 pub use rescale::*;
 pub use unavailable_mut::*;
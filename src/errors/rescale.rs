@@ -1,5 +1,10 @@
 use core::fmt;
 
+/// The error returned by [`ArrayMapFacade::try_rescale`] if the map does not
+/// fit into the new capacity `NEW_CAP`.
+///
+/// [`ArrayMapFacade::try_rescale`]: crate::ArrayMapFacade::try_rescale
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct RescaleError<const NEW_CAP: usize> {
     required_size: usize,
     old_capacity: usize,
@@ -13,29 +18,57 @@ impl<const NEW_CAP: usize> RescaleError<NEW_CAP> {
             old_capacity,
         }
     }
+
+    /// Returns the number of elements of the map that should have been
+    /// rescaled.
+    #[must_use]
+    pub const fn required_size(&self) -> usize {
+        self.required_size
+    }
+
+    /// Returns the capacity of the map that should have been rescaled.
+    #[must_use]
+    pub const fn old_capacity(&self) -> usize {
+        self.old_capacity
+    }
+}
+
+pub(super) fn fmt_rescale_error(
+    required_size: usize,
+    old_capacity: usize,
+    new_capacity: usize,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    write!(
+        f,
+        concat!(
+            "failed to rescale the map of size `{size}` and capacity `{n}`,",
+            " because the new map can hold at most `{c}` elements",
+        ),
+        size = required_size,
+        n = old_capacity,
+        c = new_capacity
+    )
 }
 
 impl<const NEW_CAP: usize> fmt::Debug for RescaleError<NEW_CAP> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("RescaleError").finish()
+        f.debug_struct("RescaleError")
+            .field("required_size", &self.required_size)
+            .field("old_capacity", &self.old_capacity)
+            .field("new_capacity", &NEW_CAP)
+            .finish()
     }
 }
 
 impl<const NEW_CAP: usize> fmt::Display for RescaleError<NEW_CAP> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            concat!(
-                "failed to rescale the map of size `{size}` and capacity `{n}`,",
-                " because the new map can hold at most `{c}` elements",
-            ),
-            size = self.required_size,
-            n = self.old_capacity,
-            c = NEW_CAP
-        )
+        fmt_rescale_error(self.required_size, self.old_capacity, NEW_CAP, f)
     }
 }
 
+impl<const NEW_CAP: usize> core::error::Error for RescaleError<NEW_CAP> {}
+
 #[cfg(all(test, feature = "alloc"))]
 mod tests {
     use super::*;
@@ -59,7 +92,7 @@ mod tests {
     fn test_rescale_error_debug() {
         assert_eq!(
             format!("{:?}", RescaleError::<4>::new(5, 6)),
-            stringify!(RescaleError)
+            "RescaleError { required_size: 5, old_capacity: 6, new_capacity: 4 }"
         );
     }
 }
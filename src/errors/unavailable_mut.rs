@@ -1,3 +1,5 @@
+use core::fmt;
+
 /// The error type for [`ArrayMap::get_each_value_mut`] and
 /// [`ArrayMap::get_each_key_value_mut`].
 ///
@@ -13,3 +15,17 @@ pub enum UnavailableMutError {
     /// This includes the index of the mutable reference in the returned array.
     Duplicate(usize),
 }
+
+impl fmt::Display for UnavailableMutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Absent => write!(f, "the requested entry is not present"),
+            Self::Duplicate(index) => write!(
+                f,
+                "a mutable reference to the requested entry has already been returned at index `{index}`"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for UnavailableMutError {}
@@ -1,3 +1,4 @@
+use core::fmt;
 use core::iter::IntoIterator;
 
 use crate::utils::ArrayExt;
@@ -38,6 +39,18 @@ pub enum CollectArrayError {
     NotEnoughItems { missing: usize },
 }
 
+impl fmt::Display for CollectArrayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotEnoughItems { missing } => {
+                write!(f, "the iterator is missing `{missing}` items to fill the array")
+            }
+        }
+    }
+}
+
+impl core::error::Error for CollectArrayError {}
+
 impl<A, const N: usize> TryFromIterator<A> for [A; N] {
     type Error = CollectArrayError;
 
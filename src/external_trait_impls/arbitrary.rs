@@ -0,0 +1,180 @@
+use core::hash::{BuildHasher, Hash};
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::raw::RawTable;
+use crate::set::{ArraySetFacade, Set};
+use crate::{ArrayMapFacade, ArrayVec};
+
+// The maps are filled until they are full, so the remaining entries are
+// ignored. Duplicate keys overwrite the earlier values.
+impl<'a, K, V, R, B> Arbitrary<'a> for ArrayMapFacade<K, V, R, B>
+where
+    K: Arbitrary<'a> + Eq + Hash,
+    V: Arbitrary<'a>,
+    R: RawTable<(K, V)> + Default,
+    B: BuildHasher + Default,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut result = Self::default();
+
+        for entry in u.arbitrary_iter::<(K, V)>()? {
+            if result.len() == result.capacity() {
+                break;
+            }
+
+            let (key, value) = entry?;
+            // the map is not full, so inserting can not fail
+            let _ = result.try_insert(key, value);
+        }
+
+        Ok(result)
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        let mut result = Self::default();
+
+        for entry in u.arbitrary_take_rest_iter::<(K, V)>()? {
+            if result.len() == result.capacity() {
+                break;
+            }
+
+            let (key, value) = entry?;
+            let _ = result.try_insert(key, value);
+        }
+
+        Ok(result)
+    }
+
+    fn size_hint(_: usize) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+impl<'a, T, R, B> Arbitrary<'a> for ArraySetFacade<T, R, B>
+where
+    T: Arbitrary<'a> + Eq + Hash,
+    R: RawTable<(T, ())> + Default,
+    B: BuildHasher + Default,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut result = Self::default();
+
+        for value in u.arbitrary_iter::<T>()? {
+            if result.len() == result.capacity() {
+                break;
+            }
+
+            let _ = result.try_insert(value?);
+        }
+
+        Ok(result)
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        let mut result = Self::default();
+
+        for value in u.arbitrary_take_rest_iter::<T>()? {
+            if result.len() == result.capacity() {
+                break;
+            }
+
+            let _ = result.try_insert(value?);
+        }
+
+        Ok(result)
+    }
+
+    fn size_hint(_: usize) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+// Like the maps, the vector is filled until it is full and the remaining
+// elements are ignored.
+impl<'a, T, const N: usize> Arbitrary<'a> for ArrayVec<T, N>
+where
+    T: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut result = Self::new();
+
+        for value in u.arbitrary_iter::<T>()? {
+            if result.is_full() {
+                break;
+            }
+
+            // the vector is not full, so pushing can not fail
+            let _ = result.try_push(value?);
+        }
+
+        Ok(result)
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        let mut result = Self::new();
+
+        for value in u.arbitrary_take_rest_iter::<T>()? {
+            if result.is_full() {
+                break;
+            }
+
+            let _ = result.try_push(value?);
+        }
+
+        Ok(result)
+    }
+
+    fn size_hint(_: usize) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+#[cfg(all(test, feature = "ahash"))]
+mod tests {
+    use arbitrary::{Arbitrary, Unstructured};
+    use pretty_assertions::assert_eq;
+
+    use crate::set::ArraySet;
+    use crate::{ArrayMap, ArrayVec, IndexMap};
+
+    /// Odd bytes, so that the iterators of [`Unstructured`] continue and each
+    /// key is different.
+    fn bytes() -> [u8; 1024] {
+        let mut result = [0; 1024];
+        let mut next = 1_u8;
+        for byte in &mut result {
+            *byte = next;
+            next = next.wrapping_add(2);
+        }
+        result
+    }
+
+    #[test]
+    fn test_arbitrary_respects_capacity() {
+        let bytes = bytes();
+
+        let map = ArrayMap::<u16, u8, 5>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        assert_eq!(map.len(), 5);
+        for (index, (key, value)) in map.iter().enumerate() {
+            assert_eq!(map.get(key), Some(value));
+            assert!(map.keys().skip(index + 1).all(|other| other != key));
+        }
+
+        let map = IndexMap::<u16, u8, 5>::arbitrary_take_rest(Unstructured::new(&bytes)).unwrap();
+        assert_eq!(map.len(), 5);
+        for (index, (key, value)) in map.iter().enumerate() {
+            assert_eq!(map.get_full(key), Some((index, key, value)));
+        }
+
+        let set = ArraySet::<u16, 5>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        assert_eq!(set.len(), 5);
+        for (index, value) in set.iter().enumerate() {
+            assert!(set.contains(value));
+            assert!(set.iter().skip(index + 1).all(|other| other != value));
+        }
+
+        let vec = ArrayVec::<u16, 5>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        assert_eq!(vec.len(), 5);
+    }
+}
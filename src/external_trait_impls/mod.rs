@@ -1,2 +1,8 @@
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "rayon")]
+mod rayon;
 #[cfg(feature = "serde")]
-mod serde;
+pub mod serde;
@@ -0,0 +1,238 @@
+//! [`proptest`] strategies for the maps, sets and vectors of this crate.
+//!
+//! The strategies never generate more elements than the capacity `N` of the
+//! collection, sizes above `N` are clamped to `N`.
+//!
+//! # Examples
+//!
+//! ```
+//! use array_map::{ArrayMap, DefaultHashBuilder};
+//! use proptest::prelude::*;
+//!
+//! fn maps() -> impl Strategy<Value = ArrayMap<u8, u8, 5>> {
+//!     array_map::proptest::array_map::<_, _, DefaultHashBuilder, 5>(any::<u8>(), any::<u8>(), 0..10)
+//! }
+//!
+//! proptest! {
+//!     fn fits(map in maps()) {
+//!         prop_assert!(map.len() <= 5);
+//!     }
+//! }
+//! # fits();
+//! ```
+use core::fmt;
+use core::hash::{BuildHasher, Hash};
+
+use proptest::arbitrary::{any_with, Arbitrary};
+use proptest::collection::{vec, SizeRange};
+use proptest::strategy::{BoxedStrategy, Strategy};
+
+use crate::set::{ArraySet, Set};
+use crate::{ArrayMap, ArrayVec, IndexMap};
+
+fn clamp_size<const N: usize>(size: impl Into<SizeRange>) -> SizeRange {
+    let size = size.into();
+
+    SizeRange::new(size.start().min(N)..=size.end_incl().min(N))
+}
+
+/// Creates a strategy that generates an [`ArrayMap`] with a number of distinct
+/// keys in the provided range (clamped to `N`).
+pub fn array_map<K, V, B, const N: usize>(
+    key: impl Strategy<Value = K>,
+    value: impl Strategy<Value = V>,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = ArrayMap<K, V, N, B>>
+where
+    K: Hash + Eq + fmt::Debug,
+    V: fmt::Debug,
+    B: BuildHasher + Default,
+{
+    let size = clamp_size::<N>(size);
+    let min = size.start();
+
+    vec((key, value), size)
+        .prop_map(|entries| {
+            let mut result = ArrayMap::default();
+            for (key, value) in entries {
+                // at most `N` entries are generated, so there is always enough space
+                let _ = result.try_insert(key, value);
+            }
+            result
+        })
+        .prop_filter("not enough distinct keys", move |map| map.len() >= min)
+}
+
+/// Creates a strategy that generates an [`IndexMap`] with a number of distinct
+/// keys in the provided range (clamped to `N`).
+pub fn index_map<K, V, B, const N: usize>(
+    key: impl Strategy<Value = K>,
+    value: impl Strategy<Value = V>,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = IndexMap<K, V, N, B>>
+where
+    K: Hash + Eq + fmt::Debug,
+    V: fmt::Debug,
+    B: BuildHasher + Default,
+{
+    let size = clamp_size::<N>(size);
+    let min = size.start();
+
+    vec((key, value), size)
+        .prop_map(|entries| {
+            let mut result = IndexMap::default();
+            for (key, value) in entries {
+                let _ = result.try_insert(key, value);
+            }
+            result
+        })
+        .prop_filter("not enough distinct keys", move |map| map.len() >= min)
+}
+
+/// Creates a strategy that generates an [`ArraySet`] with a number of
+/// distinct values in the provided range (clamped to `N`).
+pub fn array_set<T, B, const N: usize>(
+    element: impl Strategy<Value = T>,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = ArraySet<T, N, B>>
+where
+    T: Hash + Eq + fmt::Debug,
+    B: BuildHasher + Default,
+{
+    let size = clamp_size::<N>(size);
+    let min = size.start();
+
+    vec(element, size)
+        .prop_map(|values| {
+            let mut result = ArraySet::default();
+            for value in values {
+                let _ = result.try_insert(value);
+            }
+            result
+        })
+        .prop_filter("not enough distinct values", move |set| set.len() >= min)
+}
+
+/// Creates a strategy that generates an [`ArrayVec`] with a number of
+/// elements in the provided range (clamped to `N`).
+pub fn array_vec<T, const N: usize>(
+    element: impl Strategy<Value = T>,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = ArrayVec<T, N>>
+where
+    T: fmt::Debug,
+{
+    vec(element, clamp_size::<N>(size)).prop_map(|values| {
+        let mut result = ArrayVec::new();
+        for value in values {
+            // at most `N` elements are generated, so there is always enough space
+            let _ = result.try_push(value);
+        }
+        result
+    })
+}
+
+impl<K, V, B, const N: usize> Arbitrary for ArrayMap<K, V, N, B>
+where
+    K: Arbitrary + Hash + Eq + 'static,
+    V: Arbitrary + 'static,
+    K::Strategy: 'static,
+    V::Strategy: 'static,
+    B: BuildHasher + Default + 'static,
+{
+    type Parameters = (SizeRange, K::Parameters, V::Parameters);
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((size, key, value): Self::Parameters) -> Self::Strategy {
+        array_map(any_with::<K>(key), any_with::<V>(value), size).boxed()
+    }
+}
+
+impl<K, V, B, const N: usize> Arbitrary for IndexMap<K, V, N, B>
+where
+    K: Arbitrary + Hash + Eq + 'static,
+    V: Arbitrary + 'static,
+    K::Strategy: 'static,
+    V::Strategy: 'static,
+    B: BuildHasher + Default + 'static,
+{
+    type Parameters = (SizeRange, K::Parameters, V::Parameters);
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((size, key, value): Self::Parameters) -> Self::Strategy {
+        index_map(any_with::<K>(key), any_with::<V>(value), size).boxed()
+    }
+}
+
+impl<T, B, const N: usize> Arbitrary for ArraySet<T, N, B>
+where
+    T: Arbitrary + Hash + Eq + 'static,
+    T::Strategy: 'static,
+    B: BuildHasher + Default + 'static,
+{
+    type Parameters = (SizeRange, T::Parameters);
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((size, element): Self::Parameters) -> Self::Strategy {
+        array_set(any_with::<T>(element), size).boxed()
+    }
+}
+
+impl<T, const N: usize> Arbitrary for ArrayVec<T, N>
+where
+    T: Arbitrary + fmt::Debug + 'static,
+    T::Strategy: 'static,
+{
+    type Parameters = (SizeRange, T::Parameters);
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((size, element): Self::Parameters) -> Self::Strategy {
+        array_vec(any_with::<T>(element), size).boxed()
+    }
+}
+
+#[cfg(all(test, feature = "ahash"))]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::set::ArraySet;
+    use crate::{ArrayMap, ArrayVec, IndexMap};
+
+    proptest! {
+        #[test]
+        fn test_array_map_strategy(map in any::<ArrayMap<u8, u8, 5>>()) {
+            prop_assert!(map.len() <= 5);
+
+            for (index, (key, value)) in map.iter().enumerate() {
+                prop_assert_eq!(map.get(key), Some(value));
+                prop_assert!(map.keys().skip(index + 1).all(|other| other != key));
+            }
+        }
+
+        #[test]
+        fn test_index_map_strategy(map in super::index_map::<_, _, crate::DefaultHashBuilder, 3>(any::<u8>(), any::<u8>(), 2..10)) {
+            let map: IndexMap<u8, u8, 3> = map;
+            prop_assert!((2..=3).contains(&map.len()));
+
+            for (index, (key, value)) in map.iter().enumerate() {
+                prop_assert_eq!(map.get_full(key), Some((index, key, value)));
+            }
+        }
+
+        #[test]
+        fn test_array_set_strategy(set in any::<ArraySet<u8, 4>>()) {
+            prop_assert!(set.len() <= 4);
+
+            for (index, value) in set.iter().enumerate() {
+                prop_assert!(set.contains(value));
+                prop_assert!(set.iter().skip(index + 1).all(|other| other != value));
+            }
+        }
+
+        #[test]
+        fn test_array_vec_strategy(vec in super::array_vec::<_, 3>(any::<u8>(), 2..10)) {
+            let vec: ArrayVec<u8, 3> = vec;
+            prop_assert!((2..=3).contains(&vec.len()));
+        }
+    }
+}
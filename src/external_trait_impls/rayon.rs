@@ -0,0 +1,270 @@
+use alloc::vec::Vec;
+use core::hash::{BuildHasher, Hash};
+
+use rayon::iter::{IntoParallelIterator, IntoParallelRefIterator, IntoParallelRefMutIterator};
+use rayon::iter::ParallelIterator;
+
+use crate::raw::{SlotTable, TableSlot};
+use crate::{utils, ArrayMapFacade};
+
+impl<K, V, R, B> ArrayMapFacade<K, V, R, B>
+where
+    R: SlotTable<(K, V)>,
+    B: BuildHasher,
+{
+    /// Returns a parallel iterator visiting all key-value pairs.
+    ///
+    /// The slots of the map are split into chunks, which are processed in
+    /// parallel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::ArrayMap;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut map: ArrayMap<u32, u32, 1021> = ArrayMap::new();
+    /// for i in 0..1000 {
+    ///     map.try_insert(i, i * 2)?;
+    /// }
+    ///
+    /// assert_eq!(map.par_iter().map(|(_, v)| u64::from(*v)).sum::<u64>(), 999_000);
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    pub fn par_iter(&self) -> impl ParallelIterator<Item = (&K, &V)>
+    where
+        K: Sync,
+        V: Sync,
+        R::Slot: Sync,
+    {
+        self.table
+            .slots()
+            .par_iter()
+            .filter_map(|slot| slot.get().map(|(key, value)| (key, value)))
+    }
+
+    /// Returns a parallel iterator visiting all key-value pairs, with mutable
+    /// references to the values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::ArrayMap;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut map: ArrayMap<u32, u32, 1021> = ArrayMap::new();
+    /// for i in 0..1000 {
+    ///     map.try_insert(i, i)?;
+    /// }
+    ///
+    /// map.par_iter_mut().for_each(|(k, v)| *v += k);
+    /// assert_eq!(map[&500], 1000);
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    pub fn par_iter_mut(&mut self) -> impl ParallelIterator<Item = (&K, &mut V)>
+    where
+        K: Sync + Send,
+        V: Send,
+        R::Slot: Send,
+    {
+        // SAFETY: the entries are neither taken out of their slots nor are their keys
+        //         modified
+        unsafe { self.table.slots_mut() }
+            .par_iter_mut()
+            .filter_map(|slot| slot.get_mut().map(|(key, value)| (&*key, value)))
+    }
+
+    /// Returns a parallel iterator visiting all keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::ArrayMap;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut map: ArrayMap<u32, u32, 1021> = ArrayMap::new();
+    /// for i in 0..1000 {
+    ///     map.try_insert(i, i)?;
+    /// }
+    ///
+    /// assert_eq!(map.par_keys().filter(|k| **k % 2 == 0).count(), 500);
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    pub fn par_keys(&self) -> impl ParallelIterator<Item = &K>
+    where
+        K: Sync,
+        V: Sync,
+        R::Slot: Sync,
+    {
+        self.par_iter().map(|(key, _)| key)
+    }
+
+    /// Returns a parallel iterator visiting all values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::ArrayMap;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut map: ArrayMap<u32, u32, 1021> = ArrayMap::new();
+    /// for i in 0..1000 {
+    ///     map.try_insert(i, 1)?;
+    /// }
+    ///
+    /// assert_eq!(map.par_values().sum::<u32>(), 1000);
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    pub fn par_values(&self) -> impl ParallelIterator<Item = &V>
+    where
+        K: Sync,
+        V: Sync,
+        R::Slot: Sync,
+    {
+        self.par_iter().map(|(_, value)| value)
+    }
+
+    /// Returns a parallel iterator visiting all values mutably.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::ArrayMap;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut map: ArrayMap<u32, u32, 1021> = ArrayMap::new();
+    /// for i in 0..1000 {
+    ///     map.try_insert(i, 1)?;
+    /// }
+    ///
+    /// map.par_values_mut().for_each(|v| *v *= 3);
+    /// assert_eq!(map[&7], 3);
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    pub fn par_values_mut(&mut self) -> impl ParallelIterator<Item = &mut V>
+    where
+        K: Sync + Send,
+        V: Send,
+        R::Slot: Send,
+    {
+        self.par_iter_mut().map(|(_, value)| value)
+    }
+
+    /// Removes all entries from the map and returns them as a parallel
+    /// iterator.
+    ///
+    /// The map is empty after this call, even if the iterator is not consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::ArrayMap;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut map: ArrayMap<u32, u32, 1021> = ArrayMap::new();
+    /// for i in 0..1000 {
+    ///     map.try_insert(i, 1)?;
+    /// }
+    ///
+    /// assert_eq!(map.par_drain().count(), 1000);
+    /// assert!(map.is_empty());
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    pub fn par_drain(&mut self) -> impl ParallelIterator<Item = (K, V)>
+    where
+        R::Slots: IntoParallelIterator<Item = Option<(K, V)>>,
+        K: Send,
+        V: Send,
+    {
+        self.table.take_slots().into_par_iter().flatten()
+    }
+
+    /// Retains only the entries for which the predicate returns `true`.
+    ///
+    /// The predicate is called in parallel, afterwards the remaining entries
+    /// are compacted sequentially, which preserves the order of an
+    /// [`IndexMap`](crate::IndexMap).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::IndexMap;
+    ///
+    /// let mut map: IndexMap<u32, u32, 1021> = IndexMap::new();
+    /// for i in 0..1000 {
+    ///     map.try_insert(i, i)?;
+    /// }
+    ///
+    /// map.par_retain(|k, _| k % 3 == 0);
+    /// assert_eq!(map.len(), 334);
+    /// assert_eq!(map.iter().nth(1), Some((&3, &3)));
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// O(n)
+    pub fn par_retain<F>(&mut self, f: F)
+    where
+        F: Fn(&K, &mut V) -> bool + Sync + Send,
+        K: Hash + Send,
+        V: Send,
+        R::Slot: Send,
+    {
+        // SAFETY: the entries are neither taken out of their slots nor are their keys
+        //         modified
+        let keep: Vec<bool> = unsafe { self.table.slots_mut() }
+            .par_iter_mut()
+            .map(|slot| slot.get_mut().is_none_or(|(key, value)| f(key, value)))
+            .collect();
+
+        if keep.contains(&false) {
+            self.table
+                .retain_slots(&keep, utils::key_hasher(&self.build_hasher));
+        }
+    }
+}
+
+#[cfg(all(test, feature = "ahash"))]
+mod tests {
+    use alloc::vec::Vec;
+    use pretty_assertions::assert_eq;
+    use rayon::iter::ParallelIterator;
+
+    use crate::{ArrayMap, IndexMap};
+
+    #[test]
+    fn test_par_retain_array_map() {
+        let mut map: ArrayMap<u32, u32, 61> = ArrayMap::new();
+        for i in 0..60 {
+            map.try_insert(i, i).unwrap();
+        }
+
+        map.par_retain(|k, v| {
+            *v += 1;
+            k % 2 == 1
+        });
+
+        assert_eq!(map.len(), 30);
+        for i in 0..60 {
+            assert_eq!(map.get(&i), (i % 2 == 1).then(|| i + 1).as_ref());
+        }
+    }
+
+    #[test]
+    fn test_par_drain_index_map() {
+        let mut map: IndexMap<u32, u32, 31> = IndexMap::new();
+        for i in 0..20 {
+            map.try_insert(i, i).unwrap();
+        }
+
+        let mut drained = map.par_drain().collect::<Vec<_>>();
+        drained.sort_unstable();
+
+        assert_eq!(drained, (0..20).map(|i| (i, i)).collect::<Vec<_>>());
+        assert!(map.is_empty());
+
+        map.try_insert(1, 1).unwrap();
+        assert_eq!(map.get(&1), Some(&1));
+    }
+}
@@ -1,16 +1,354 @@
+//! Adapter modules to configure how maps are deserialized.
+//!
+//! By default deserializing a map fails as soon as the map is full and later
+//! duplicate keys overwrite the earlier values. The modules in [`overflow`]
+//! and [`duplicates`] can be used with `#[serde(with = "...")]` to choose a
+//! different policy, while [`deserialize_map`] allows to combine them freely.
+//!
+//! # Examples
+//!
+//! ```
+//! use array_map::ArrayMap;
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Readings {
+//!     #[serde(with = "array_map::serde::overflow::truncate")]
+//!     sensors: ArrayMap<String, f32, 3>,
+//! }
+//!
+//! let json = r#"{ "sensors": { "a": 1.0, "b": 2.0, "c": 3.0, "d": 4.0 } }"#;
+//! let readings: Readings = serde_json::from_str(json)?;
+//!
+//! assert_eq!(readings.sensors.len(), 3);
+//! assert_eq!(readings.sensors.contains_key("d"), false);
+//! # Ok::<_, serde_json::Error>(())
+//! ```
+use core::fmt;
+use core::hash::{BuildHasher, Hash};
+use serde::de::{Deserializer, Error, MapAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::raw::RawTable;
+use crate::{ArrayMapFacade, IndexMap};
+
+/// Decides what happens with new keys after the map is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overflow {
+    /// Fail with an error.
+    Error,
+    /// Keep the first entries and skip the remaining ones.
+    Truncate,
+}
+
+/// Decides what happens if a key appears more than once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Duplicates {
+    /// Fail with an error.
+    Error,
+    /// Keep the value that appeared first.
+    First,
+    /// Keep the value that appeared last.
+    Last,
+}
+
+enum OverflowMode<M> {
+    Error,
+    Truncate,
+    /// Removes an entry from the full map to make space for the new one.
+    Evict(fn(&mut M)),
+}
+
+struct PolicyVisitor<K, V, R: RawTable<(K, V)>, B> {
+    overflow: OverflowMode<ArrayMapFacade<K, V, R, B>>,
+    duplicates: Duplicates,
+}
+
+impl<'de, K, V, R, B> Visitor<'de> for PolicyVisitor<K, V, R, B>
+where
+    K: serde::Deserialize<'de> + Eq + Hash,
+    V: serde::Deserialize<'de>,
+    R: RawTable<(K, V)> + Default,
+    B: BuildHasher + Default,
+{
+    type Value = ArrayMapFacade<K, V, R, B>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a map")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut result = ArrayMapFacade::with_hasher(B::default());
+        let capacity = result.capacity();
+        let mut seen = 0_usize;
+
+        while let Some((key, value)) = map.next_entry()? {
+            seen += 1;
+
+            if let Some(old_value) = result.get_mut(&key) {
+                match self.duplicates {
+                    Duplicates::Error => {
+                        return Err(A::Error::custom(format_args!(
+                            "duplicate key in a map with capacity {capacity} after {seen} entries"
+                        )));
+                    }
+                    Duplicates::First => {}
+                    Duplicates::Last => *old_value = value,
+                }
+
+                continue;
+            }
+
+            if result.len() == capacity {
+                match self.overflow {
+                    OverflowMode::Error => {}
+                    OverflowMode::Truncate => continue,
+                    OverflowMode::Evict(evict) => evict(&mut result),
+                }
+            }
+
+            result.try_insert(key, value).map_err(|_| {
+                A::Error::custom(format_args!(
+                    "too many entries for a map with capacity {capacity}, got at least {seen} entries"
+                ))
+            })?;
+        }
+
+        Ok(result)
+    }
+}
+
+/// Deserializes a map with the provided [`Overflow`] and [`Duplicates`]
+/// policies.
+///
+/// With [`Overflow::Truncate`] only the entries that have been kept are
+/// checked for duplicates.
+///
+/// # Errors
+///
+/// If the deserializer fails or one of the policies is [`Error`]. The error
+/// reports the capacity of the map and the number of entries seen so far.
+///
+/// [`Error`]: Overflow::Error
+///
+/// # Examples
+///
+/// ```
+/// use array_map::serde::{deserialize_map, Duplicates, Overflow};
+/// use array_map::ArrayMap;
+///
+/// let mut deserializer = serde_json::Deserializer::from_str(r#"{ "a": 1, "a": 2 }"#);
+/// let map: ArrayMap<String, u32, 5> =
+///     deserialize_map(&mut deserializer, Overflow::Error, Duplicates::First)?;
+///
+/// assert_eq!(map["a"], 1);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+pub fn deserialize_map<'de, D, K, V, R, B>(
+    deserializer: D,
+    overflow: Overflow,
+    duplicates: Duplicates,
+) -> Result<ArrayMapFacade<K, V, R, B>, D::Error>
+where
+    D: Deserializer<'de>,
+    K: serde::Deserialize<'de> + Eq + Hash,
+    V: serde::Deserialize<'de>,
+    R: RawTable<(K, V)> + Default,
+    B: BuildHasher + Default,
+{
+    let overflow = match overflow {
+        Overflow::Error => OverflowMode::Error,
+        Overflow::Truncate => OverflowMode::Truncate,
+    };
+
+    deserializer.deserialize_map(PolicyVisitor {
+        overflow,
+        duplicates,
+    })
+}
+
+/// Deserializes an [`IndexMap`] that keeps the last `N` entries, by removing
+/// the oldest entry whenever a new key does not fit.
+///
+/// A duplicate key does not move its entry to the back of the map.
+///
+/// # Errors
+///
+/// If the deserializer fails or `duplicates` is [`Duplicates::Error`]. The
+/// error reports the capacity of the map and the number of entries seen so
+/// far.
+///
+/// # Examples
+///
+/// ```
+/// use array_map::serde::{deserialize_index_map_keep_last, Duplicates};
+/// use array_map::IndexMap;
+///
+/// let mut deserializer = serde_json::Deserializer::from_str(r#"{ "a": 1, "b": 2, "c": 3 }"#);
+/// let map: IndexMap<String, u32, 2> =
+///     deserialize_index_map_keep_last(&mut deserializer, Duplicates::Last)?;
+///
+/// assert_eq!(map.get_entry_at(0), Some((&"b".to_string(), &2)));
+/// assert_eq!(map.get_entry_at(1), Some((&"c".to_string(), &3)));
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+pub fn deserialize_index_map_keep_last<'de, D, K, V, B, const N: usize>(
+    deserializer: D,
+    duplicates: Duplicates,
+) -> Result<IndexMap<K, V, N, B>, D::Error>
+where
+    D: Deserializer<'de>,
+    K: serde::Deserialize<'de> + Eq + Hash,
+    V: serde::Deserialize<'de>,
+    B: BuildHasher + Default,
+{
+    deserializer.deserialize_map(PolicyVisitor {
+        overflow: OverflowMode::Evict(|map: &mut IndexMap<K, V, N, B>| {
+            map.shift_remove_index(0);
+        }),
+        duplicates,
+    })
+}
+
+/// Serializes a value with its [`Serialize`] implementation.
+///
+/// This exists, so that the adapter modules can be used with
+/// `#[serde(with = "...")]`.
+///
+/// # Errors
+///
+/// If the serializer fails.
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize + ?Sized,
+    S: Serializer,
+{
+    value.serialize(serializer)
+}
+
+/// Generates an adapter module, whose `deserialize` function calls
+/// [`deserialize_map`] with fixed policies.
+macro_rules! policy_module {
+    (
+        $(#[$attr:meta])*
+        $name:ident: Overflow::$overflow:ident, Duplicates::$duplicates:ident,
+        errors: $errors:literal $(,)?
+    ) => {
+        $(#[$attr])*
+        pub mod $name {
+            use core::hash::{BuildHasher, Hash};
+            use serde::de::{Deserialize, Deserializer};
+
+            pub use crate::serde::serialize;
+            use crate::raw::RawTable;
+            use crate::serde::{deserialize_map, Duplicates, Overflow};
+            use crate::ArrayMapFacade;
+
+            /// # Errors
+            ///
+            #[doc = $errors]
+            pub fn deserialize<'de, D, K, V, R, B>(
+                deserializer: D,
+            ) -> Result<ArrayMapFacade<K, V, R, B>, D::Error>
+            where
+                D: Deserializer<'de>,
+                K: Deserialize<'de> + Eq + Hash,
+                V: Deserialize<'de>,
+                R: RawTable<(K, V)> + Default,
+                B: BuildHasher + Default,
+            {
+                deserialize_map(deserializer, Overflow::$overflow, Duplicates::$duplicates)
+            }
+        }
+    };
+}
+
+/// Adapters that choose what happens if a map is full.
+///
+/// Duplicate keys keep the value that appeared last.
+pub mod overflow {
+    policy_module! {
+        /// Fails if there are more entries than the map can hold.
+        error: Overflow::Error, Duplicates::Last,
+        errors: "If the deserializer fails or the map is full.",
+    }
+
+    policy_module! {
+        /// Keeps the first entries that fit in the map and skips the others.
+        truncate: Overflow::Truncate, Duplicates::Last,
+        errors: "If the deserializer fails.",
+    }
+
+    /// Keeps the last entries of an [`IndexMap`](crate::IndexMap).
+    ///
+    /// See [`deserialize_index_map_keep_last`](crate::serde::deserialize_index_map_keep_last).
+    pub mod keep_last {
+        use core::hash::{BuildHasher, Hash};
+        use serde::de::{Deserialize, Deserializer};
+
+        pub use crate::serde::serialize;
+        use crate::serde::{deserialize_index_map_keep_last, Duplicates};
+        use crate::IndexMap;
+
+        /// # Errors
+        ///
+        /// If the deserializer fails.
+        pub fn deserialize<'de, D, K, V, B, const N: usize>(
+            deserializer: D,
+        ) -> Result<IndexMap<K, V, N, B>, D::Error>
+        where
+            D: Deserializer<'de>,
+            K: Deserialize<'de> + Eq + Hash,
+            V: Deserialize<'de>,
+            B: BuildHasher + Default,
+        {
+            deserialize_index_map_keep_last(deserializer, Duplicates::Last)
+        }
+    }
+}
+
+/// Adapters that choose what happens if a key appears more than once.
+///
+/// All of them fail if there are more entries than the map can hold.
+pub mod duplicates {
+    policy_module! {
+        /// Fails if a key appears more than once.
+        error: Overflow::Error, Duplicates::Error,
+        errors: "If the deserializer fails, the map is full or a key is duplicated.",
+    }
+
+    policy_module! {
+        /// Keeps the value that appeared first.
+        first: Overflow::Error, Duplicates::First,
+        errors: "If the deserializer fails or the map is full.",
+    }
+
+    policy_module! {
+        /// Keeps the value that appeared last.
+        last: Overflow::Error, Duplicates::Last,
+        errors: "If the deserializer fails or the map is full.",
+    }
+}
+
 mod map {
-    use core::fmt;
     use core::hash::{BuildHasher, Hash};
-    use core::marker::PhantomData;
-    use serde::de::{Deserialize, Deserializer, Error, MapAccess, Visitor};
+    use serde::de::{Deserialize, Deserializer};
     use serde::ser::{Serialize, Serializer};
 
-    use crate::ArrayMap;
+    use super::{Duplicates, Overflow};
+    use crate::raw::{RawTable, RawTableIter};
+    use crate::ArrayMapFacade;
 
-    impl<K, V, B, const N: usize> Serialize for ArrayMap<K, V, N, B>
+    // The entries are serialized in the iteration order of the table, so an
+    // `IndexMap` keeps its insertion order on a round-trip.
+    impl<K, V, R, B> Serialize for ArrayMapFacade<K, V, R, B>
     where
         K: Serialize + Eq + Hash,
         V: Serialize,
+        R: RawTableIter<(K, V)>,
         B: BuildHasher,
     {
         fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -21,50 +359,326 @@ mod map {
         }
     }
 
-    impl<'de, K, V, B, const N: usize> Deserialize<'de> for ArrayMap<K, V, N, B>
+    impl<'de, K, V, R, B> Deserialize<'de> for ArrayMapFacade<K, V, R, B>
     where
         K: Deserialize<'de> + Eq + Hash,
         V: Deserialize<'de>,
+        R: RawTable<(K, V)> + Default,
+        B: BuildHasher + Default,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            super::deserialize_map(deserializer, Overflow::Error, Duplicates::Last)
+        }
+    }
+}
+
+mod set {
+    use core::fmt;
+    use core::hash::{BuildHasher, Hash};
+    use core::marker::PhantomData;
+    use serde::de::{Deserialize, Deserializer, Error, SeqAccess, Visitor};
+    use serde::ser::{Serialize, Serializer};
+
+    use crate::raw::{RawTable, RawTableIter};
+    use crate::set::{ArraySetFacade, Set};
+
+    impl<T, R, B> Serialize for ArraySetFacade<T, R, B>
+    where
+        T: Serialize + Eq + Hash,
+        R: RawTableIter<(T, ())>,
+        B: BuildHasher,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_seq(self.iter())
+        }
+    }
+
+    impl<'de, T, R, B> Deserialize<'de> for ArraySetFacade<T, R, B>
+    where
+        T: Deserialize<'de> + Eq + Hash,
+        R: RawTable<(T, ())> + Default,
         B: BuildHasher + Default,
     {
         fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
         where
             D: Deserializer<'de>,
         {
-            struct MapVisitor<K, V, B: BuildHasher, const N: usize> {
-                marker: PhantomData<ArrayMap<K, V, N, B>>,
+            struct SeqVisitor<T, R: RawTable<(T, ())>, B> {
+                marker: PhantomData<ArraySetFacade<T, R, B>>,
             }
 
-            impl<'de, K, V, B, const N: usize> Visitor<'de> for MapVisitor<K, V, B, N>
+            impl<'de, T, R, B> Visitor<'de> for SeqVisitor<T, R, B>
             where
-                K: Deserialize<'de> + Eq + Hash,
-                V: Deserialize<'de>,
+                T: Deserialize<'de> + Eq + Hash,
+                R: RawTable<(T, ())> + Default,
                 B: BuildHasher + Default,
             {
-                type Value = ArrayMap<K, V, N, B>;
+                type Value = ArraySetFacade<T, R, B>;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str("a sequence")
+                }
+
+                fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+                where
+                    A: SeqAccess<'de>,
+                {
+                    let mut result = ArraySetFacade::default();
+
+                    while let Some(value) = seq.next_element()? {
+                        result.try_insert(value).map_err(A::Error::custom)?;
+                    }
+
+                    Ok(result)
+                }
+            }
+
+            let visitor = SeqVisitor {
+                marker: PhantomData,
+            };
+            deserializer.deserialize_seq(visitor)
+        }
+    }
+}
+
+mod array_vec {
+    use core::fmt;
+    use core::marker::PhantomData;
+    use serde::de::{Deserialize, Deserializer, Error, SeqAccess, Visitor};
+    use serde::ser::{Serialize, Serializer};
+
+    use crate::ArrayVec;
+
+    impl<T: Serialize, const N: usize> Serialize for ArrayVec<T, N> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_seq(self.iter())
+        }
+    }
+
+    impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for ArrayVec<T, N> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            struct SeqVisitor<T, const N: usize> {
+                marker: PhantomData<ArrayVec<T, N>>,
+            }
+
+            impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for SeqVisitor<T, N> {
+                type Value = ArrayVec<T, N>;
 
                 fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                    formatter.write_str("a map")
+                    write!(formatter, "a sequence with at most {N} elements")
                 }
 
-                fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+                fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
                 where
-                    A: MapAccess<'de>,
+                    A: SeqAccess<'de>,
                 {
-                    let mut result = ArrayMap::with_hasher(B::default());
+                    let mut result = ArrayVec::new();
 
-                    while let Some((key, value)) = map.next_entry()? {
-                        result.try_insert(key, value).map_err(A::Error::custom)?;
+                    while let Some(value) = seq.next_element()? {
+                        result.try_push(value).map_err(A::Error::custom)?;
                     }
 
                     Ok(result)
                 }
             }
 
-            let visitor = MapVisitor {
+            let visitor = SeqVisitor {
                 marker: PhantomData,
             };
-            deserializer.deserialize_map(visitor)
+            deserializer.deserialize_seq(visitor)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "ahash"))]
+mod tests {
+    use core::fmt::{self, Write};
+
+    use pretty_assertions::assert_eq;
+    use serde::Deserialize;
+
+    use crate::ext::IteratorExt;
+    use crate::set::ArraySet;
+    use crate::{index_map, ArrayMap, ArrayVec, IndexMap};
+
+    /// A fixed size buffer to format the error messages into, without
+    /// requiring an allocation.
+    struct Buffer {
+        bytes: [u8; 128],
+        len: usize,
+    }
+
+    impl Buffer {
+        fn format(value: impl fmt::Display) -> Self {
+            let mut buffer = Self {
+                bytes: [0; 128],
+                len: 0,
+            };
+            write!(buffer, "{value}").unwrap();
+            buffer
+        }
+
+        fn as_str(&self) -> &str {
+            core::str::from_utf8(&self.bytes[..self.len]).unwrap()
+        }
+    }
+
+    impl Write for Buffer {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let end = self.len + s.len();
+            self.bytes
+                .get_mut(self.len..end)
+                .ok_or(fmt::Error)?
+                .copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
         }
     }
+
+    #[test]
+    fn test_index_map_round_trip_keeps_order() {
+        let map: IndexMap<&str, u32, 3> = index_map! {
+            "c" => 3,
+            "a" => 1,
+            "b" => 2,
+        };
+
+        let json = serde_json::to_string(&map).unwrap();
+        assert_eq!(json, r#"{"c":3,"a":1,"b":2}"#);
+
+        let result: IndexMap<&str, u32, 3> = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            result.iter().try_collect::<[_; 3]>(),
+            Ok([(&"c", &3), (&"a", &1), (&"b", &2)])
+        );
+    }
+
+    #[test]
+    fn test_array_map_round_trip() {
+        let mut map: ArrayMap<u32, u32, 7> = ArrayMap::new();
+        map.try_insert(1, 10).unwrap();
+        map.try_insert(2, 20).unwrap();
+
+        let json = serde_json::to_string(&map).unwrap();
+        let result: ArrayMap<u32, u32, 7> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(result, map);
+    }
+
+    #[test]
+    fn test_map_overflow_errors() {
+        let error = serde_json::from_str::<ArrayMap<u32, u32, 1>>(r#"{"1":1,"2":2}"#).unwrap_err();
+
+        assert!(Buffer::format(error)
+            .as_str()
+            .starts_with("too many entries for a map with capacity 1, got at least 2 entries"));
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Truncated {
+        #[serde(with = "crate::serde::overflow::truncate")]
+        map: IndexMap<u32, u32, 2>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct KeepLast {
+        #[serde(with = "crate::serde::overflow::keep_last")]
+        map: IndexMap<u32, u32, 2>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Unique {
+        #[serde(with = "crate::serde::duplicates::error")]
+        map: ArrayMap<u32, u32, 5>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct First {
+        #[serde(with = "crate::serde::duplicates::first")]
+        map: ArrayMap<u32, u32, 5>,
+    }
+
+    #[test]
+    fn test_overflow_truncate() {
+        let result: Truncated =
+            serde_json::from_str(r#"{"map":{"1":1,"2":2,"3":3,"1":4}}"#).unwrap();
+
+        assert_eq!(
+            result.map.iter().try_collect::<[_; 2]>(),
+            Ok([(&1, &4), (&2, &2)])
+        );
+    }
+
+    #[test]
+    fn test_overflow_keep_last() {
+        let result: KeepLast =
+            serde_json::from_str(r#"{"map":{"1":1,"2":2,"3":3,"4":4}}"#).unwrap();
+
+        assert_eq!(
+            result.map.iter().try_collect::<[_; 2]>(),
+            Ok([(&3, &3), (&4, &4)])
+        );
+    }
+
+    #[test]
+    fn test_duplicates_error() {
+        let result: Unique = serde_json::from_str(r#"{"map":{"1":1,"2":2}}"#).unwrap();
+        assert_eq!(result.map.len(), 2);
+
+        assert!(serde_json::from_str::<Unique>(r#"{"map":{"1":1,"2":2,"1":3}}"#).is_err());
+    }
+
+    #[test]
+    fn test_duplicates_error_message() {
+        let error = serde_json::from_str::<Unique>(r#"{"map":{"1":1,"2":2,"1":3}}"#).unwrap_err();
+
+        assert!(Buffer::format(error)
+            .as_str()
+            .starts_with("duplicate key in a map with capacity 5 after 3 entries"));
+    }
+
+    #[test]
+    fn test_duplicates_first() {
+        let result: First = serde_json::from_str(r#"{"map":{"1":1,"2":2,"1":3}}"#).unwrap();
+
+        assert_eq!(result.map[&1], 1);
+        assert_eq!(result.map[&2], 2);
+    }
+
+    #[test]
+    fn test_array_set_round_trip() {
+        let json = "[3,1,2]";
+        let set: ArraySet<u32, 5> = serde_json::from_str(json).unwrap();
+
+        let mut values = set.iter().copied().try_collect::<[u32; 3]>().unwrap();
+        values.sort_unstable();
+        assert_eq!(values, [1, 2, 3]);
+
+        let result: ArraySet<u32, 5> =
+            serde_json::from_str(&serde_json::to_string(&set).unwrap()).unwrap();
+        let mut values = result.iter().copied().try_collect::<[u32; 3]>().unwrap();
+        values.sort_unstable();
+        assert_eq!(values, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_array_vec_round_trip() {
+        let vec: ArrayVec<u32, 4> = serde_json::from_str("[3,1,2]").unwrap();
+        assert_eq!(vec.as_slice(), &[3, 1, 2]);
+        assert_eq!(serde_json::to_string(&vec).unwrap(), "[3,1,2]");
+
+        assert!(serde_json::from_str::<ArrayVec<u32, 2>>("[3,1,2]").is_err());
+    }
 }
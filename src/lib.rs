@@ -2,13 +2,14 @@
 #![feature(
     array_methods,
     never_type,
-    maybe_uninit_uninit_array,
     maybe_uninit_array_assume_init,
     try_trait_v2,
     stmt_expr_attributes,
     generic_associated_types
 )]
 #![cfg_attr(feature = "nightly", feature(core_intrinsics))]
+#![cfg_attr(feature = "generic_const_exprs", feature(generic_const_exprs))]
+#![cfg_attr(feature = "generic_const_exprs", allow(incomplete_features))]
 #![warn(clippy::nursery, clippy::pedantic)]
 #![allow(
     clippy::module_name_repetitions,
@@ -16,6 +17,8 @@
     clippy::redundant_pub_crate
 )]
 
+pub mod archive;
+pub mod array_vec;
 mod errors;
 pub mod ext;
 mod external_trait_impls;
@@ -24,8 +27,13 @@ mod raw;
 pub mod set;
 mod utils;
 
+pub use crate::array_vec::ArrayVec;
 pub use crate::map::{ArrayMap, ArrayMapFacade, DefaultHashBuilder, IndexMap};
 pub use errors::*;
+#[cfg(feature = "proptest")]
+pub use external_trait_impls::proptest;
+#[cfg(feature = "serde")]
+pub use external_trait_impls::serde;
 
-#[cfg(feature = "alloc")]
+#[cfg(any(feature = "alloc", feature = "rayon"))]
 extern crate alloc;
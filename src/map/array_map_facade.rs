@@ -1,5 +1,5 @@
 use core::borrow::Borrow;
-use core::hash::{BuildHasher, Hash};
+use core::hash::{BuildHasher, Hash, Hasher};
 use core::marker::PhantomData;
 use core::ops::Index;
 use core::{fmt, mem};
@@ -8,7 +8,9 @@ use crate::errors::{CapacityError, RescaleError, UnavailableMutError};
 use crate::ext::{TryExtend, TryFromIterator};
 use crate::map::entry::Entry;
 use crate::map::iter::{Drain, DrainFilter, Iter, IterMut, Keys, Values, ValuesMut};
+use crate::map::map::{Map, MapIter};
 use crate::map::occupied::OccupiedEntry;
+use crate::map::reseed::ReseedBuildHasher;
 use crate::map::vacant::VacantEntry;
 use crate::raw::{ArrayTable, FixedSizeTable, RawEntryBuilder, RawTable, RawTableIter};
 use crate::{unreachable_unchecked, utils};
@@ -25,8 +27,11 @@ pub type ArrayMap<K, V, const N: usize, B = DefaultHashBuilder> =
 
 #[derive(Copy, Clone)]
 pub struct ArrayMapFacade<K, V, R: RawTable<(K, V)>, B = DefaultHashBuilder> {
-    pub(super) table: R,
-    pub(super) build_hasher: B,
+    pub(crate) table: R,
+    pub(crate) build_hasher: B,
+    /// The number of entries at the last reseed of
+    /// [`ArrayMapFacade::try_insert_guarded`].
+    reseed_len: usize,
     _p: PhantomData<(K, V)>,
 }
 
@@ -88,12 +93,24 @@ impl<K, V, R: RawTable<(K, V)> + Default, B: BuildHasher> ArrayMapFacade<K, V, R
         Self {
             table: R::default(),
             build_hasher,
+            reseed_len: 0,
             _p: PhantomData,
         }
     }
 }
 
 impl<K, V, R: RawTable<(K, V)>, B: BuildHasher> ArrayMapFacade<K, V, R, B> {
+    /// Creates a map from a table, whose entries have been hashed with the
+    /// `build_hasher`.
+    pub(crate) const fn from_parts(table: R, build_hasher: B) -> Self {
+        Self {
+            table,
+            build_hasher,
+            reseed_len: 0,
+            _p: PhantomData,
+        }
+    }
+
     /// Returns the number of elements the map can hold in total.
     ///
     /// The returned value, will be equal to the const generic `N`.
@@ -182,6 +199,11 @@ where
     /// Gets the given key's corresponding entry in the map for in-place
     /// manipulation.
     ///
+    /// # Errors
+    ///
+    /// Returns a [`CapacityError`] if the key is not present in the map and
+    /// the map is already full.
+    ///
     /// # Examples
     ///
     /// ```
@@ -214,7 +236,10 @@ where
                 )))
             }
         } else if self.table.len() == self.table.capacity() {
-            Err(CapacityError)
+            Err(CapacityError::new(
+                self.table.capacity(),
+                self.table.len() + 1,
+            ))
         } else {
             unsafe {
                 Ok(Entry::Vacant(VacantEntry::new(
@@ -226,6 +251,39 @@ where
         }
     }
 
+    /// Gets the given key's corresponding entry in the map for in-place
+    /// manipulation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is not present in the map and the map is already
+    /// full. Use [`ArrayMapFacade::entry`] to handle this case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::ArrayMap;
+    ///
+    /// let mut letters: ArrayMap<char, u32, 26> = ArrayMap::new();
+    ///
+    /// for c in "a short treatise on fungi".chars() {
+    ///     *letters.entry_or_panic(c).or_insert(0) += 1;
+    /// }
+    ///
+    /// assert_eq!(letters[&'s'], 2);
+    /// assert_eq!(letters[&'t'], 3);
+    /// ```
+    #[cfg(feature = "panic")]
+    #[track_caller]
+    pub fn entry_or_panic(&mut self, key: K) -> Entry<'_, K, V, R, B> {
+        let capacity = self.capacity();
+
+        match self.entry(key) {
+            Ok(entry) => entry,
+            Err(_) => utils::capacity_overflow(capacity),
+        }
+    }
+
     /// Inserts a key-value pair into the map.
     ///
     /// If the map did not have this key present, [`None`] is returned.
@@ -275,6 +333,34 @@ where
         Ok(self.entry(key)?.insert(value))
     }
 
+    /// Inserts a key-value pair into the map.
+    ///
+    /// If the map did not have this key present, [`None`] is returned.
+    ///
+    /// If the map did have this key present, the value is updated, and the old
+    /// value is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is not present in the map and the map is already
+    /// full. Use [`ArrayMapFacade::try_insert`] to handle this case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::ArrayMap;
+    ///
+    /// let mut map: ArrayMap<i32, &str, 2> = ArrayMap::new();
+    /// assert_eq!(map.insert(37, "a"), None);
+    /// assert_eq!(map.insert(37, "b"), Some("a"));
+    /// assert_eq!(map[&37], "b");
+    /// ```
+    #[cfg(feature = "panic")]
+    #[track_caller]
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.entry_or_panic(key).insert(value)
+    }
+
     /// Returns a reference to the value corresponding to the key.
     ///
     /// The key may be any borrowed form of the map's key type, but
@@ -769,7 +855,10 @@ where
     where
         F: FnMut(&K, &mut V) -> bool,
     {
-        self.drain_filter(|key, value| !(f(key, value)));
+        self.table.retain(
+            |(key, value)| f(key, value),
+            utils::key_hasher(&self.build_hasher),
+        );
     }
 
     /// Creates a raw immutable entry builder for the `ArrayMap`.
@@ -796,6 +885,251 @@ where
     }
 }
 
+impl<K, V, R, B> ArrayMapFacade<K, V, R, B>
+where
+    K: Eq + Hash,
+    R: RawTable<(K, V)>,
+    B: ReseedBuildHasher,
+{
+    /// Inserts a key-value pair into the map like
+    /// [`ArrayMapFacade::try_insert`], but protects the map against
+    /// hash-flooding.
+    ///
+    /// If the new entry ends up further away from the slot its hash points to,
+    /// than [`ReseedBuildHasher::max_probe_length`] allows, it is assumed that
+    /// the keys have been chosen to collide. The map will then switch to a
+    /// freshly seeded hasher and rebuild its table (see
+    /// [`ArrayMapFacade::reseed`]).
+    ///
+    /// Keys can also collide under every seed, in which case rebuilding the
+    /// table does not help. To bound the cost of the rebuilds, the map is only
+    /// reseeded again once it has twice as many entries as at the last reseed.
+    ///
+    /// # Errors
+    ///
+    /// If the key is not present in the map and the map is full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ahash::RandomState;
+    /// use array_map::ArrayMap;
+    ///
+    /// let mut map: ArrayMap<u32, &str, 13, RandomState> =
+    ///     ArrayMap::with_hasher(RandomState::new());
+    ///
+    /// assert_eq!(map.try_insert_guarded(1, "a")?, None);
+    /// assert_eq!(map.try_insert_guarded(1, "b")?, Some("a"));
+    /// assert_eq!(map[&1], "b");
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    pub fn try_insert_guarded(&mut self, key: K, value: V) -> Result<Option<V>, CapacityError> {
+        let hash = utils::make_hash::<K, K, B>(&self.build_hasher, &key);
+
+        if let Some(ident) = self.table.find(hash, |(k, _)| k.eq(&key)) {
+            // SAFETY: remove has not been called after the ident has been created
+            let (_, old_value) = unsafe { self.table.get_unchecked_mut(ident) };
+            return Ok(Some(mem::replace(old_value, value)));
+        }
+
+        if self.table.len() == self.table.capacity() {
+            return Err(CapacityError::new(
+                self.table.capacity(),
+                self.table.len() + 1,
+            ));
+        }
+
+        let result =
+            self.table
+                .try_insert(hash, (key, value), utils::key_hasher(&self.build_hasher));
+
+        let Ok(ident) = result else {
+            unreachable_unchecked!("there must be free space in the table")
+        };
+
+        let max_probe_length = self
+            .build_hasher
+            .max_probe_length(self.len(), self.capacity());
+
+        if self.table.probe_length(&ident, hash) > max_probe_length
+            && self.len() >= self.reseed_len.saturating_mul(2)
+        {
+            self.reseed_len = self.len();
+            self.reseed();
+        }
+
+        Ok(None)
+    }
+
+    /// Replaces the seed of the map's [`BuildHasher`] and rebuilds the table
+    /// with the new hashes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ahash::RandomState;
+    /// use array_map::ArrayMap;
+    ///
+    /// let mut map: ArrayMap<u32, &str, 13, RandomState> =
+    ///     ArrayMap::with_hasher(RandomState::new());
+    ///
+    /// map.try_insert(1, "a")?;
+    /// map.try_insert(2, "b")?;
+    ///
+    /// map.reseed();
+    ///
+    /// assert_eq!(map.get(&1), Some(&"a"));
+    /// assert_eq!(map.get(&2), Some(&"b"));
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// O(n)
+    pub fn reseed(&mut self) {
+        self.build_hasher.reseed();
+        self.table.rehash(utils::key_hasher(&self.build_hasher));
+    }
+}
+
+impl<K, V, R, B> ArrayMapFacade<K, V, R, B>
+where
+    K: Eq + Hash,
+    R: RawTable<(K, V)> + Default,
+    B: BuildHasher + Clone,
+{
+    /// Splits the map into two maps with the same capacity. The first map
+    /// contains the first `at` entries in iteration order and the second one
+    /// the remaining entries.
+    ///
+    /// For an [`IndexMap`] this splits the map at the index `at`.
+    ///
+    /// [`IndexMap`]: crate::IndexMap
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::{index_map, IndexMap};
+    ///
+    /// let map: IndexMap<_, _, 3> = index_map!(1 => "a", 2 => "b", 3 => "c");
+    ///
+    /// let (left, right) = map.split(1);
+    ///
+    /// assert_eq!(left.iter().collect::<Vec<_>>(), vec![(&1, &"a")]);
+    /// assert_eq!(right.iter().collect::<Vec<_>>(), vec![(&2, &"b"), (&3, &"c")]);
+    /// assert_eq!(right.capacity(), 3);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// O(n)
+    #[must_use]
+    pub fn split(self, at: usize) -> (Self, Self) {
+        let mut index = 0;
+
+        self.partition(|_, _| {
+            index += 1;
+            index <= at
+        })
+    }
+
+    /// Splits the map into two maps with the same capacity. The first map
+    /// contains all entries for which `f` returned `true` and the second one
+    /// all entries for which it returned `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::{array_map, ArrayMap};
+    ///
+    /// let map: ArrayMap<_, _, 4> = array_map!(1 => "a", 2 => "b", 3 => "c", 4 => "d");
+    ///
+    /// let (even, odd) = map.partition(|key, _| key % 2 == 0);
+    ///
+    /// assert!(even.eq_map(&array_map!(2 => "b", 4 => "d")));
+    /// assert_eq!(odd.len(), 2);
+    /// assert_eq!(odd.get(&3), Some(&"c"));
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// O(n)
+    #[must_use]
+    pub fn partition<F>(mut self, mut f: F) -> (Self, Self)
+    where
+        F: FnMut(&K, &V) -> bool,
+    {
+        let mut left = Self::with_build_hasher(self.build_hasher.clone());
+        let mut right = Self::with_build_hasher(self.build_hasher);
+
+        for (key, value) in self.table.drain() {
+            let target = if f(&key, &value) { &mut left } else { &mut right };
+
+            if let Err(error) = target.try_insert(key, value) {
+                unreachable_unchecked!("{}", error);
+            }
+        }
+
+        (left, right)
+    }
+}
+
+#[cfg(feature = "generic_const_exprs")]
+impl<K, V, B, const N: usize> ArrayMap<K, V, N, B>
+where
+    K: Eq + Hash,
+    B: BuildHasher,
+{
+    /// Merges `other` into this map and returns a map that is large enough
+    /// to hold the entries of both maps, so the merge can not fail.
+    ///
+    /// If both maps contain the same key, the value of `other` is kept.
+    ///
+    /// The capacity of the result is computed with the incomplete
+    /// `generic_const_exprs` feature of the compiler, so this is only
+    /// available with the `generic_const_exprs` feature of this crate and the
+    /// calling crate has to enable the compiler feature as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![allow(incomplete_features)]
+    /// #![feature(generic_const_exprs)]
+    /// use array_map::{array_map, ArrayMap};
+    ///
+    /// let first: ArrayMap<_, _, 2> = array_map!(1 => "a", 2 => "b");
+    /// let second: ArrayMap<_, _, 3> = array_map!(2 => "c", 3 => "d", 4 => "e");
+    ///
+    /// let merged: ArrayMap<_, _, 5> = first.merge_into_larger(second);
+    ///
+    /// assert_eq!(merged.len(), 4);
+    /// assert_eq!(merged[&2], "c");
+    /// assert_eq!(merged[&4], "e");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// O(n + m)
+    #[must_use]
+    pub fn merge_into_larger<C, const M: usize>(
+        mut self,
+        mut other: ArrayMap<K, V, M, C>,
+    ) -> ArrayMap<K, V, { N + M }, B>
+    where
+        [(); N + M]:,
+    {
+        let mut result = ArrayMapFacade::with_build_hasher(self.build_hasher);
+
+        for (key, value) in self.table.drain().chain(other.table.drain()) {
+            if let Err(error) = result.try_insert(key, value) {
+                unreachable_unchecked!("{}", error);
+            }
+        }
+
+        result
+    }
+}
+
 impl<K, V, R, B> ArrayMapFacade<K, V, R, B>
 where
     R: RawTableIter<(K, V)>,
@@ -953,6 +1287,46 @@ where
     pub fn values_mut(&mut self) -> ValuesMut<'_, K, V, R> {
         ValuesMut::new(self.iter_mut())
     }
+
+    /// Returns `true` if both maps contain the same entries, ignoring their
+    /// order.
+    ///
+    /// Unlike `==`, the maps may have different tables, capacities or hashers.
+    /// A generic [`PartialEq`] implementation would prevent the compiler from
+    /// inferring the capacity in comparisons like `map == ArrayMap::new()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::{ArrayMap, IndexMap};
+    ///
+    /// let mut a: ArrayMap<&str, u32, 5> = ArrayMap::new();
+    /// a.try_insert("a", 1)?;
+    /// a.try_insert("b", 2)?;
+    ///
+    /// let mut b: IndexMap<&str, u32, 11> = IndexMap::new();
+    /// b.try_insert("b", 2)?;
+    /// b.try_insert("a", 1)?;
+    ///
+    /// assert_eq!(a.eq_map(&b), true);
+    ///
+    /// b.try_insert("c", 3)?;
+    /// assert_eq!(a.eq_map(&b), false);
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    #[must_use]
+    pub fn eq_map<W, Q, C>(&self, other: &ArrayMapFacade<K, W, Q, C>) -> bool
+    where
+        K: Eq + Hash,
+        V: PartialEq<W>,
+        Q: RawTableIter<(K, W)>,
+        C: BuildHasher,
+    {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(key, value)| other.get(key).is_some_and(|v| *value == *v))
+    }
 }
 
 impl<K, Q: ?Sized, V, R, B> Index<&Q> for ArrayMapFacade<K, V, R, B>
@@ -974,6 +1348,61 @@ where
     }
 }
 
+impl<K, V, R, B> Map<K, V> for ArrayMapFacade<K, V, R, B>
+where
+    K: Hash + Eq,
+    R: RawTable<(K, V)>,
+    B: BuildHasher,
+{
+    type Error = CapacityError;
+
+    fn len(&self) -> usize {
+        Self::len(self)
+    }
+
+    fn get(&self, key: &K) -> Option<&V> {
+        Self::get(self, key)
+    }
+
+    fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        Self::get_mut(self, key)
+    }
+
+    fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, Self::Error> {
+        Self::try_insert(self, key, value)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        Self::remove(self, key)
+    }
+}
+
+impl<K, V, R, B> MapIter<K, V> for ArrayMapFacade<K, V, R, B>
+where
+    K: Hash + Eq,
+    R: RawTableIter<(K, V)>,
+    B: BuildHasher,
+{
+    type Iter<'a> = Iter<'a, K, V, R>
+    where
+        K: 'a,
+        V: 'a,
+        Self: 'a;
+    type IterMut<'a> = IterMut<'a, K, V, R>
+    where
+        K: 'a,
+        V: 'a,
+        Self: 'a;
+
+    fn iter(&self) -> Self::Iter<'_> {
+        Self::iter(self)
+    }
+
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        Self::iter_mut(self)
+    }
+}
+
 impl<'a, K, V, R, B> IntoIterator for &'a ArrayMapFacade<K, V, R, B>
 where
     R: RawTableIter<(K, V)>,
@@ -1043,12 +1472,73 @@ where
     B: BuildHasher,
 {
     fn eq(&self, other: &Self) -> bool {
-        if self.len() != other.len() {
-            return false;
-        }
+        self.eq_map(other)
+    }
+}
 
-        self.iter()
-            .all(|(key, value)| other.get(key).map_or(false, |v| *value == *v))
+#[cfg(feature = "std")]
+impl<K, V, W, R, B, S> PartialEq<::std::collections::HashMap<K, W, S>> for ArrayMapFacade<K, V, R, B>
+where
+    K: Eq + Hash,
+    V: PartialEq<W>,
+    R: RawTableIter<(K, V)>,
+    B: BuildHasher,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &::std::collections::HashMap<K, W, S>) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(key, value)| other.get(key).is_some_and(|v| *value == *v))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, W, R, B, S> PartialEq<ArrayMapFacade<K, W, R, B>> for ::std::collections::HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    V: PartialEq<W>,
+    R: RawTableIter<(K, W)>,
+    B: BuildHasher,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &ArrayMapFacade<K, W, R, B>) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(key, value)| other.get(key).is_some_and(|v| *value == *v))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, W, R, B> PartialEq<::std::collections::BTreeMap<K, W>> for ArrayMapFacade<K, V, R, B>
+where
+    K: Eq + Hash + Ord,
+    V: PartialEq<W>,
+    R: RawTableIter<(K, V)>,
+    B: BuildHasher,
+{
+    fn eq(&self, other: &::std::collections::BTreeMap<K, W>) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(key, value)| other.get(key).is_some_and(|v| *value == *v))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, W, R, B> PartialEq<ArrayMapFacade<K, W, R, B>> for ::std::collections::BTreeMap<K, V>
+where
+    K: Eq + Hash + Ord,
+    V: PartialEq<W>,
+    R: RawTableIter<(K, W)>,
+    B: BuildHasher,
+{
+    fn eq(&self, other: &ArrayMapFacade<K, W, R, B>) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(key, value)| other.get(key).is_some_and(|v| *value == *v))
     }
 }
 
@@ -1061,6 +1551,20 @@ where
 {
 }
 
+// The hash does not depend on the order of the entries or the hasher of the
+// map, because neither of them is relevant for `Eq`.
+impl<K, V, R, B> Hash for ArrayMapFacade<K, V, R, B>
+where
+    K: Eq + Hash,
+    V: Hash,
+    R: RawTableIter<(K, V)>,
+    B: BuildHasher,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        utils::hash_unordered(self.iter(), state);
+    }
+}
+
 impl<K, V, R, B, const N: usize> From<[(K, V); N]> for ArrayMapFacade<K, V, R, B>
 where
     K: Eq + Hash,
@@ -1133,9 +1637,54 @@ where
     }
 }
 
+#[cfg(feature = "panic")]
+impl<K, V, R, B> FromIterator<(K, V)> for ArrayMapFacade<K, V, R, B>
+where
+    K: Eq + Hash,
+    R: RawTable<(K, V)> + Default,
+    B: BuildHasher + Default,
+{
+    #[track_caller]
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        let mut result = Self::with_build_hasher(B::default());
+        result.extend(iter);
+        result
+    }
+}
+
+#[cfg(feature = "panic")]
+impl<K, V, R, B> Extend<(K, V)> for ArrayMapFacade<K, V, R, B>
+where
+    K: Eq + Hash,
+    R: RawTable<(K, V)>,
+    B: BuildHasher,
+{
+    #[track_caller]
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+#[cfg(feature = "panic")]
+impl<'a, K, V, R, B> Extend<(&'a K, &'a V)> for ArrayMapFacade<K, V, R, B>
+where
+    K: Eq + Hash + Copy,
+    V: Copy,
+    R: RawTable<(K, V)>,
+    B: BuildHasher,
+{
+    #[track_caller]
+    fn extend<T: IntoIterator<Item = (&'a K, &'a V)>>(&mut self, iter: T) {
+        self.extend(iter.into_iter().map::<(K, V), _>(|(k, v)| (*k, *v)));
+    }
+}
+
 #[cfg(all(test, feature = "ahash"))]
 mod tests {
-    use crate::array_map;
+    use crate::ext::IteratorExt;
+    use crate::{array_map, index_map, IndexMap};
 
     use super::*;
     use pretty_assertions::assert_eq;
@@ -1150,6 +1699,72 @@ mod tests {
         assert_eq!(map.get("other"), Some(&"world2"));
     }
 
+    #[test]
+    #[cfg(feature = "panic")]
+    fn test_panicking_api() {
+        let mut map: ArrayMap<_, _, 3> = [(1, 2), (3, 4)].into_iter().collect();
+        map.extend([(1, 3), (5, 6)]);
+        map.extend(&[(5, 7)].into_iter().collect::<ArrayMap<_, _, 1>>());
+
+        assert_eq!(map.insert(3, 5), Some(4));
+        *map.entry_or_panic(1).or_insert(0) += 1;
+        assert_eq!(map, array_map!(1 => 4, 3 => 5, 5 => 7));
+    }
+
+    #[test]
+    #[cfg(feature = "panic")]
+    #[should_panic(expected = "capacity 2")]
+    fn test_insert_overflow_panics() {
+        let mut map: ArrayMap<_, _, 2> = ArrayMap::new();
+        map.insert(1, 1);
+        map.insert(2, 2);
+        map.insert(1, 3);
+        map.insert(3, 3);
+    }
+
+    #[test]
+    #[cfg(feature = "panic")]
+    #[should_panic(expected = "capacity 2")]
+    fn test_from_iter_overflow_panics() {
+        let _: ArrayMap<_, _, 2> = (0..3).map(|i| (i, i)).collect();
+    }
+
+    #[test]
+    #[cfg(feature = "generic_const_exprs")]
+    fn test_merge_into_larger() {
+        let first: ArrayMap<_, _, 3> = array_map!(1 => 'a', 2 => 'b', 3 => 'c');
+        let second: ArrayMap<_, _, 2> = array_map!(3 => 'd', 4 => 'e');
+
+        let merged: ArrayMap<_, _, 5> = first.merge_into_larger(second);
+        assert_eq!(merged.capacity(), 5);
+        assert!(merged.eq_map(&array_map!(1 => 'a', 2 => 'b', 3 => 'd', 4 => 'e')));
+    }
+
+    #[test]
+    fn test_split_partition() {
+        let map: ArrayMap<_, _, 5> = array_map! {
+            @infer,
+            1 => 'a',
+            2 => 'b',
+            3 => 'd',
+            4 => 'e',
+        }
+        .unwrap();
+
+        let (small, large) = map.partition(|key, _| *key < 3);
+        assert!(small.eq_map(&array_map!(1 => 'a', 2 => 'b')));
+        assert!(large.eq_map(&array_map!(3 => 'd', 4 => 'e')));
+
+        let map: IndexMap<_, _, 4> = index_map!(4 => 'a', 3 => 'b', 2 => 'c', 1 => 'd');
+        let (left, right) = map.clone().split(3);
+        assert_eq!(left.keys().copied().try_collect::<[_; 3]>(), Ok([4, 3, 2]));
+        assert_eq!(right.keys().copied().try_collect::<[_; 1]>(), Ok([1]));
+
+        let (left, right) = map.split(5);
+        assert_eq!(left.len(), 4);
+        assert!(right.is_empty());
+    }
+
     // TODO: better test!
     #[test]
     fn test_get_each_mut() {
@@ -1205,6 +1820,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_hash_ignores_the_map_hasher() {
+        let hash = |map: &ArrayMap<u32, u32, 7, ahash::RandomState>| {
+            let mut hasher = DefaultHashBuilder::default().build_hasher();
+            map.hash(&mut hasher);
+            core::hash::Hasher::finish(&hasher)
+        };
+
+        let mut a = ArrayMap::with_hasher(ahash::RandomState::with_seeds(1, 2, 3, 4));
+        let mut b = ArrayMap::with_hasher(ahash::RandomState::with_seeds(5, 6, 7, 8));
+        let mut c = ArrayMap::with_hasher(ahash::RandomState::with_seeds(1, 2, 3, 4));
+
+        for i in 0..5 {
+            a.try_insert(i, i).unwrap();
+            b.try_insert(4 - i, 4 - i).unwrap();
+            c.try_insert(i, i + 1).unwrap();
+        }
+
+        assert_eq!(a, b);
+        assert_eq!(hash(&a), hash(&b));
+        assert_ne!(hash(&a), hash(&c));
+    }
+
     #[test]
     fn test_remove_shift_with_smaller_hash() {
         let mut map: ArrayMap<HasHash, usize, 5, _> = array_map! {
@@ -1564,6 +2202,217 @@ mod tests {
         );
     }
 
+    /// Hashes the written `u64` to itself multiplied by the seed, so the seed
+    /// `0` causes every key to collide.
+    #[derive(Default)]
+    struct SeededBuildHasher(u64);
+
+    impl BuildHasher for SeededBuildHasher {
+        type Hasher = SeededHasher;
+
+        fn build_hasher(&self) -> Self::Hasher {
+            SeededHasher(self.0, 0)
+        }
+    }
+
+    impl ReseedBuildHasher for SeededBuildHasher {
+        fn reseed(&mut self) {
+            self.0 += 1;
+        }
+
+        fn max_probe_length(&self, _: usize, _: usize) -> usize {
+            2
+        }
+    }
+
+    struct SeededHasher(u64, u64);
+
+    impl core::hash::Hasher for SeededHasher {
+        fn finish(&self) -> u64 {
+            self.0 * self.1
+        }
+
+        fn write(&mut self, _: &[u8]) {
+            unimplemented!()
+        }
+
+        fn write_u64(&mut self, value: u64) {
+            self.1 = value;
+        }
+    }
+
+    #[test]
+    fn test_try_insert_guarded_reseeds() {
+        let mut map: ArrayMap<HasHash, u64, 5, _> =
+            ArrayMap::with_build_hasher(SeededBuildHasher::default());
+
+        for i in 0..3 {
+            assert_eq!(map.try_insert_guarded(HasHash(i, 0), i), Ok(None));
+        }
+
+        // the third key had to probe 2 slots, which is still acceptable
+        assert_eq!(map.build_hasher().0, 0);
+
+        assert_eq!(map.try_insert_guarded(HasHash(3, 0), 3), Ok(None));
+
+        // the fourth key exceeded the limit, so the map should have been rebuilt
+        assert_eq!(map.build_hasher().0, 1);
+        assert_eq!(
+            map.table,
+            ArrayTable::from_array([
+                Some((HasHash(0, 0), 0)),
+                Some((HasHash(1, 0), 1)),
+                Some((HasHash(2, 0), 2)),
+                Some((HasHash(3, 0), 3)),
+                None,
+            ])
+        );
+
+        assert_eq!(map.try_insert_guarded(HasHash(2, 0), 4), Ok(Some(2)));
+        assert_eq!(map.try_insert_guarded(HasHash(4, 0), 5), Ok(None));
+        assert_eq!(
+            map.try_insert_guarded(HasHash(5, 0), 6),
+            Err(CapacityError::new(5, 6))
+        );
+        assert_eq!(map.build_hasher().0, 1);
+    }
+
+    /// Like [`SeededBuildHasher`], but with the default probe length limit.
+    #[derive(Default)]
+    struct DefaultLimitBuildHasher(SeededBuildHasher);
+
+    impl BuildHasher for DefaultLimitBuildHasher {
+        type Hasher = SeededHasher;
+
+        fn build_hasher(&self) -> Self::Hasher {
+            self.0.build_hasher()
+        }
+    }
+
+    impl ReseedBuildHasher for DefaultLimitBuildHasher {
+        fn reseed(&mut self) {
+            self.0.reseed();
+        }
+    }
+
+    #[test]
+    fn test_try_insert_guarded_default_limit() {
+        let mut map: ArrayMap<HasHash, u64, 32, _> =
+            ArrayMap::with_build_hasher(DefaultLimitBuildHasher::default());
+
+        assert_eq!(map.build_hasher().max_probe_length(1, 32), 16);
+        assert_eq!(map.build_hasher().max_probe_length(20, 32), 18);
+
+        for i in 0..19 {
+            assert_eq!(map.try_insert_guarded(HasHash(i, 0), i), Ok(None));
+        }
+
+        // the 19th key had to probe 18 slots, which is still acceptable
+        assert_eq!(map.build_hasher().0 .0, 0);
+
+        assert_eq!(map.try_insert_guarded(HasHash(19, 0), 19), Ok(None));
+
+        assert_eq!(map.build_hasher().0 .0, 1);
+        for i in 0..20 {
+            assert_eq!(map.get(&HasHash(i, 0)), Some(&i));
+        }
+    }
+
+    #[test]
+    fn test_try_insert_guarded_near_full() {
+        let mut map: ArrayMap<HasHash, u64, 13, _> =
+            ArrayMap::with_build_hasher(DefaultLimitBuildHasher(SeededBuildHasher(1)));
+
+        // the keys occupy the slots 1 to 12 without colliding
+        for i in 1..13 {
+            assert_eq!(map.try_insert_guarded(HasHash(i, 0), i), Ok(None));
+        }
+
+        // the last key has to probe the whole table, which is expected for a
+        // full table and must not cause a reseed
+        assert_eq!(map.try_insert_guarded(HasHash(14, 0), 14), Ok(None));
+        assert_eq!(map.build_hasher().0 .0, 1);
+        assert_eq!(map.get(&HasHash(14, 0)), Some(&14));
+    }
+
+    #[test]
+    fn test_try_insert_guarded_seed_independent_collisions() {
+        let mut map: ArrayMap<HasHash, u64, 64, _> =
+            ArrayMap::with_build_hasher(DefaultLimitBuildHasher::default());
+
+        // every key has the same hash with any seed
+        for i in 0..64 {
+            assert_eq!(map.try_insert_guarded(HasHash(1, i), i), Ok(None));
+        }
+
+        // without the backoff the map would be rebuilt on every insert after
+        // the first reseed
+        assert_eq!(map.build_hasher().0 .0, 2);
+        for i in 0..64 {
+            assert_eq!(map.get(&HasHash(1, i)), Some(&i));
+        }
+    }
+
+    #[test]
+    fn test_reseed_index_map_keeps_order() {
+        let mut map: crate::IndexMap<HasHash, u64, 5, _> =
+            crate::IndexMap::with_build_hasher(SeededBuildHasher::default());
+
+        for i in (0..5).rev() {
+            map.try_insert(HasHash(i, 0), i).unwrap();
+        }
+
+        map.reseed();
+
+        for (index, i) in (0..5).rev().enumerate() {
+            assert_eq!(map.get_entry_at(index), Some((&HasHash(i, 0), &i)));
+            assert_eq!(map.get(&HasHash(i, 0)), Some(&i));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_eq_std_maps() {
+        use std::collections::{BTreeMap, HashMap};
+
+        let mut map: ArrayMap<u32, u32, 7> = ArrayMap::new();
+        let mut hash_map = HashMap::new();
+        let mut btree_map = BTreeMap::new();
+
+        for i in 0..5 {
+            map.try_insert(i, i * 2).unwrap();
+            hash_map.insert(i, i * 2);
+            btree_map.insert(i, i * 2);
+        }
+
+        assert_eq!(map, hash_map);
+        assert_eq!(hash_map, map);
+        assert_eq!(map, btree_map);
+        assert_eq!(btree_map, map);
+
+        hash_map.insert(1, 0);
+        btree_map.remove(&1);
+        assert_ne!(map, hash_map);
+        assert_ne!(map, btree_map);
+    }
+
+    #[test]
+    fn test_eq_map_different_tables() {
+        let mut map: ArrayMap<u32, u32, 7> = ArrayMap::new();
+        let mut index_map: IndexMap<u32, u32, 5> = IndexMap::new();
+
+        for i in 0..5 {
+            map.try_insert(i, i).unwrap();
+            index_map.try_insert(4 - i, 4 - i).unwrap();
+        }
+
+        assert_eq!(map.eq_map(&index_map), true);
+        assert_eq!(index_map.eq_map(&map), true);
+
+        index_map.try_insert(0, 1).unwrap();
+        assert_eq!(map.eq_map(&index_map), false);
+    }
+
     #[test]
     fn test_try_extend() {
         //
@@ -1603,4 +2452,31 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_iterators_exact_size() {
+        let mut map: ArrayMap<_, _, 3> = array_map!(1 => 'a', 2 => 'b', 3 => 'c');
+        map.remove(&2);
+
+        assert_eq!(map.iter().len(), 2);
+        assert_eq!(map.keys().len(), 2);
+        assert_eq!(map.values_mut().len(), 2);
+
+        let mut iter = map.iter();
+        let first = iter.next_back();
+        assert_eq!(iter.len(), 1);
+        assert_ne!(iter.next(), first);
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+
+        let mut drain = map.drain();
+        assert_eq!(drain.len(), 2);
+        drain.next();
+        assert_eq!(drain.len(), 1);
+        mem::drop(drain);
+        assert!(map.is_empty());
+
+        map.try_insert(4, 'd').unwrap();
+        assert_eq!(map.into_iter().len(), 1);
+    }
 }
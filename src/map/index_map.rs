@@ -1,19 +1,64 @@
 use core::borrow::Borrow;
-use core::fmt;
-use core::hash::{BuildHasher, Hash};
+use core::cmp::Ordering;
+use core::hash::{BuildHasher, Hash, Hasher};
 use core::ops::{Bound, Index, RangeBounds};
+use core::{fmt, mem};
 
-use crate::map::iter::DrainRange;
-use crate::map::{ArrayMapFacade, DefaultHashBuilder};
+use crate::array_vec::{ArrayVec, VecStorage};
+use crate::errors::{CapacityError, UnavailableMutError};
+use crate::map::iter::{DrainRange, ExtractIf};
+use crate::map::{ArrayMapFacade, DefaultHashBuilder, Slice};
 use crate::raw::{ArrayIndexTable, RawTable, TableIndex};
+use crate::unreachable_unchecked;
 use crate::utils::{self, UnwrapExpectExt};
 
-pub type IndexMap<K, V, const N: usize, B = DefaultHashBuilder> =
-    ArrayMapFacade<K, V, ArrayIndexTable<(K, V), N>, B>;
-
-impl<K, V, const N: usize, B: BuildHasher> IndexMap<K, V, N, B>
+/// An [`ArrayMapFacade`] that keeps the insertion order of its entries.
+///
+/// By default the entries are stored in an [`ArrayVec`], which has to drop
+/// them, so the map is not [`Copy`]. With a [`CopyArrayVec`] as the storage
+/// `E` the map is [`Copy`] if the entries and the hasher are [`Copy`].
+///
+/// [`CopyArrayVec`]: crate::array_vec::CopyArrayVec
+///
+/// # Examples
+///
+/// ```
+/// use core::hash::BuildHasher;
+///
+/// use ahash::AHasher;
+/// use array_map::array_vec::CopyArrayVec;
+/// use array_map::ext::IteratorExt;
+/// use array_map::IndexMap;
+///
+/// #[derive(Clone, Copy, Default)]
+/// struct CopyState;
+///
+/// impl BuildHasher for CopyState {
+///     type Hasher = AHasher;
+///
+///     fn build_hasher(&self) -> Self::Hasher {
+///         AHasher::default()
+///     }
+/// }
+///
+/// let mut map: IndexMap<u32, &str, 5, CopyState, CopyArrayVec<(u32, &str), 5>> =
+///     IndexMap::with_hasher(CopyState);
+/// map.try_insert(1, "a")?;
+///
+/// let copy = map;
+/// map.try_insert(2, "b")?;
+///
+/// assert_eq!(copy.keys().try_collect(), Ok([&1]));
+/// assert_eq!(map.keys().try_collect(), Ok([&1, &2]));
+/// # Ok::<_, array_map::CapacityError>(())
+/// ```
+pub type IndexMap<K, V, const N: usize, B = DefaultHashBuilder, E = ArrayVec<(K, V), N>> =
+    ArrayMapFacade<K, V, ArrayIndexTable<(K, V), N, E>, B>;
+
+impl<K, V, const N: usize, B: BuildHasher, E> IndexMap<K, V, N, B, E>
 where
     K: Hash + Eq,
+    E: VecStorage<(K, V), N>,
 {
     /// Shortens the map, keeping the first `n` elements and removing the rest.
     ///
@@ -35,10 +80,12 @@ where
     /// assert_eq!(map.pop(), None);
     /// # Ok::<_, array_map::CapacityError>(())
     /// ```
+    ///
+    /// # Complexity
+    ///
+    /// O(n)
     pub fn truncate(&mut self, n: usize) {
-        while self.len() > n && !self.is_empty() {
-            self.pop();
-        }
+        self.table.truncate(n);
     }
 
     /// Removes the entry from the map and shifts all entries inserted after
@@ -79,9 +126,7 @@ where
         unsafe {
             let ident = self.table.find(hash, |(k, _)| qkey.eq(k.borrow()))?;
 
-            let entry = self
-                .table
-                .shift_remove(ident, utils::key_hasher(&self.build_hasher));
+            let entry = self.table.shift_remove(ident);
             Some(entry)
         }
     }
@@ -140,10 +185,7 @@ where
             let ident = self
                 .table
                 .ident_from_index(index, utils::key_hasher(&self.build_hasher))?;
-            Some(
-                self.table
-                    .shift_remove(ident, utils::key_hasher(&self.build_hasher)),
-            )
+            Some(self.table.shift_remove(ident))
         }
     }
 
@@ -236,6 +278,391 @@ where
         Some((key, value))
     }
 
+    /// Returns the index of the key in the map or `None` if the key is not
+    /// present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::{index_map, IndexMap};
+    ///
+    /// let map: IndexMap<&str, u32, 5> = index_map! {
+    ///     @infer,
+    ///     "a" => 1,
+    ///     "b" => 2,
+    /// }?;
+    ///
+    /// assert_eq!(map.get_index_of("b"), Some(1));
+    /// assert_eq!(map.get_index_of("c"), None);
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    #[must_use]
+    pub fn get_index_of<Q>(&self, qkey: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let hash = utils::make_hash::<K, Q, B>(&self.build_hasher, qkey);
+        let ident = self.table.find(hash, |(k, _)| qkey.eq(k.borrow()))?;
+
+        // SAFETY: the ident has just been returned by `find`
+        Some(unsafe { self.table.index_from_ident(ident) }.index())
+    }
+
+    /// Returns the index of the key in the map, the key and a reference to
+    /// its value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::{index_map, IndexMap};
+    ///
+    /// let map: IndexMap<&str, u32, 5> = index_map! {
+    ///     @infer,
+    ///     "a" => 1,
+    ///     "b" => 2,
+    /// }?;
+    ///
+    /// assert_eq!(map.get_full("b"), Some((1, &"b", &2)));
+    /// assert_eq!(map.get_full("c"), None);
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    #[must_use]
+    pub fn get_full<Q>(&self, qkey: &Q) -> Option<(usize, &K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.get_index_of(qkey)?;
+        let (key, value) = self.table.get_index(index)?;
+
+        Some((index, key, value))
+    }
+
+    /// Returns the index of the key in the map, the key and a mutable
+    /// reference to its value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::{index_map, IndexMap};
+    ///
+    /// let mut map: IndexMap<&str, u32, 5> = index_map! {
+    ///     @infer,
+    ///     "a" => 1,
+    ///     "b" => 2,
+    /// }?;
+    ///
+    /// if let Some((index, _, value)) = map.get_full_mut("b") {
+    ///     *value += index as u32;
+    /// }
+    ///
+    /// assert_eq!(map.get("b"), Some(&3));
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    #[must_use]
+    pub fn get_full_mut<Q>(&mut self, qkey: &Q) -> Option<(usize, &K, &mut V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.get_index_of(qkey)?;
+        let (key, value) = self.table.get_index_mut(index)?;
+
+        Some((index, key, value))
+    }
+
+    /// Returns the first entry of the map or `None` if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::{index_map, IndexMap};
+    ///
+    /// let map: IndexMap<&str, u32, 5> = index_map! {
+    ///     @infer,
+    ///     "a" => 1,
+    ///     "b" => 2,
+    /// }?;
+    ///
+    /// assert_eq!(map.first(), Some((&"a", &1)));
+    /// assert_eq!(IndexMap::<&str, u32, 5>::new().first(), None);
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    #[must_use]
+    pub fn first(&self) -> Option<(&K, &V)> {
+        self.get_entry_at(0)
+    }
+
+    /// Returns the first entry of the map with a mutable reference to its
+    /// value or `None` if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::{index_map, IndexMap};
+    ///
+    /// let mut map: IndexMap<&str, u32, 5> = index_map! {
+    ///     @infer,
+    ///     "a" => 1,
+    ///     "b" => 2,
+    /// }?;
+    ///
+    /// if let Some((_, value)) = map.first_mut() {
+    ///     *value = 10;
+    /// }
+    ///
+    /// assert_eq!(map.first(), Some((&"a", &10)));
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    #[must_use]
+    pub fn first_mut(&mut self) -> Option<(&K, &mut V)> {
+        self.get_entry_at_mut(0)
+    }
+
+    /// Returns the last entry of the map or `None` if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::{index_map, IndexMap};
+    ///
+    /// let map: IndexMap<&str, u32, 5> = index_map! {
+    ///     @infer,
+    ///     "a" => 1,
+    ///     "b" => 2,
+    /// }?;
+    ///
+    /// assert_eq!(map.last(), Some((&"b", &2)));
+    /// assert_eq!(IndexMap::<&str, u32, 5>::new().last(), None);
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    #[must_use]
+    pub fn last(&self) -> Option<(&K, &V)> {
+        self.get_entry_at(self.len().checked_sub(1)?)
+    }
+
+    /// Returns the last entry of the map with a mutable reference to its
+    /// value or `None` if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::{index_map, IndexMap};
+    ///
+    /// let mut map: IndexMap<&str, u32, 5> = index_map! {
+    ///     @infer,
+    ///     "a" => 1,
+    ///     "b" => 2,
+    /// }?;
+    ///
+    /// if let Some((_, value)) = map.last_mut() {
+    ///     *value = 20;
+    /// }
+    ///
+    /// assert_eq!(map.last(), Some((&"b", &20)));
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    #[must_use]
+    pub fn last_mut(&mut self) -> Option<(&K, &mut V)> {
+        self.get_entry_at_mut(self.len().checked_sub(1)?)
+    }
+
+    /// Returns a slice of all entries in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::{index_map, IndexMap};
+    ///
+    /// let map: IndexMap<&str, u32, 5> = index_map! {
+    ///     @infer,
+    ///     "a" => 1,
+    ///     "b" => 2,
+    /// }?;
+    ///
+    /// let slice = map.as_slice();
+    /// assert_eq!(slice.len(), 2);
+    /// assert_eq!(slice.first(), Some((&"a", &1)));
+    /// assert_eq!(slice[1], 2);
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    #[must_use]
+    pub fn as_slice(&self) -> &Slice<K, V> {
+        Slice::from_slice(self.table.as_slice())
+    }
+
+    /// Returns a mutable slice of all entries in the map.
+    ///
+    /// Only the values can be modified through the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::{index_map, IndexMap};
+    ///
+    /// let mut map: IndexMap<&str, u32, 5> = index_map! {
+    ///     @infer,
+    ///     "a" => 1,
+    ///     "b" => 2,
+    /// }?;
+    ///
+    /// for (_, value) in map.as_mut_slice() {
+    ///     *value *= 10;
+    /// }
+    ///
+    /// assert_eq!(map.get("b"), Some(&20));
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    #[must_use]
+    pub fn as_mut_slice(&mut self) -> &mut Slice<K, V> {
+        // SAFETY: the slice does not allow modifying the keys
+        Slice::from_mut_slice(unsafe { self.table.as_mut_slice() })
+    }
+
+    /// Returns a slice of the entries in the range or `None` if the range is
+    /// out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::ext::IteratorExt;
+    /// use array_map::{index_map, IndexMap};
+    ///
+    /// let map: IndexMap<u32, &str, 11> = index_map! {
+    ///     @infer,
+    ///     0 => "a",
+    ///     1 => "b",
+    ///     2 => "c",
+    ///     3 => "d",
+    ///     4 => "e",
+    ///     5 => "f",
+    /// }?;
+    ///
+    /// let slice = map.get_range(2..5).unwrap();
+    /// assert_eq!(
+    ///     slice.iter().try_collect(),
+    ///     Ok([(&2, &"c"), (&3, &"d"), (&4, &"e")])
+    /// );
+    /// assert_eq!(map.get_range(4..7), None);
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    #[must_use]
+    pub fn get_range<R: RangeBounds<usize>>(&self, range: R) -> Option<&Slice<K, V>> {
+        self.as_slice().get_range(range)
+    }
+
+    /// Returns a mutable slice of the entries in the range or `None` if the
+    /// range is out of bounds.
+    ///
+    /// Only the values can be modified through the slice.
+    #[must_use]
+    pub fn get_range_mut<R: RangeBounds<usize>>(&mut self, range: R) -> Option<&mut Slice<K, V>> {
+        self.as_mut_slice().get_range_mut(range)
+    }
+
+    /// Attempts to get mutable references to the values at `M` indices at
+    /// once, with immutable references to the corresponding keys.
+    ///
+    /// Returns an array of length `M` with the results of each query. An
+    /// index that is out of bounds results in
+    /// `Err(UnavailableMutError::Absent)`. For soundness, at most one
+    /// mutable reference will be returned to any
+    /// value. An `Err(UnavailableMutError::Duplicate(i))` in the returned
+    /// array indicates that a mutable reference to the value already occurs
+    /// at index `i` in the returned array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::{index_map, IndexMap, UnavailableMutError};
+    ///
+    /// let mut map: IndexMap<&str, u32, 5> = index_map! {
+    ///     @infer,
+    ///     "a" => 1,
+    ///     "b" => 2,
+    ///     "c" => 3,
+    /// }?;
+    ///
+    /// assert_eq!(
+    ///     map.get_disjoint_indices_mut([2, 0, 2, 3]),
+    ///     [
+    ///         Ok((&"c", &mut 3)),
+    ///         Ok((&"a", &mut 1)),
+    ///         Err(UnavailableMutError::Duplicate(0)),
+    ///         Err(UnavailableMutError::Absent),
+    ///     ]
+    /// );
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    pub fn get_disjoint_indices_mut<const M: usize>(
+        &mut self,
+        indices: [usize; M],
+    ) -> [Result<(&K, &mut V), UnavailableMutError>; M] {
+        self.table.get_disjoint_indices_mut(indices).map(|entry| {
+            let (key, value) = entry?;
+            Ok((&*key, value))
+        })
+    }
+
+    /// Inserts the key-value pair into the map and returns its index with the
+    /// old value (if present).
+    ///
+    /// If the key is already present, its value is replaced, but it keeps its
+    /// position. Otherwise the entry is appended to the end of the map.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CapacityError`] if the key is not present and the map is
+    /// already full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::IndexMap;
+    ///
+    /// let mut map: IndexMap<&str, u32, 5> = IndexMap::new();
+    ///
+    /// assert_eq!(map.try_insert_full("a", 1)?, (0, None));
+    /// assert_eq!(map.try_insert_full("b", 2)?, (1, None));
+    /// assert_eq!(map.try_insert_full("a", 3)?, (0, Some(1)));
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    pub fn try_insert_full(
+        &mut self,
+        key: K,
+        value: V,
+    ) -> Result<(usize, Option<V>), CapacityError> {
+        self.try_move_insert(key, value, Option::unwrap_or)
+    }
+
+    /// Inserts the key-value pair into the map and returns its index with the
+    /// old value (if present).
+    ///
+    /// See [`IndexMap::try_insert_full`] for more.
+    ///
+    /// # Panics
+    ///
+    /// If the key is not present and the map is already full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::IndexMap;
+    ///
+    /// let mut map: IndexMap<&str, u32, 5> = IndexMap::new();
+    ///
+    /// assert_eq!(map.insert_full("a", 1), (0, None));
+    /// assert_eq!(map.insert_full("a", 2), (0, Some(1)));
+    /// ```
+    #[cfg(feature = "panic")]
+    #[track_caller]
+    pub fn insert_full(&mut self, key: K, value: V) -> (usize, Option<V>) {
+        let capacity = self.capacity();
+        self.try_insert_full(key, value)
+            .unwrap_or_else(|_| utils::capacity_overflow(capacity))
+    }
+
     /// Swaps the position of the two entries `a` and `b`.
     ///
     /// # Panics
@@ -382,7 +809,8 @@ where
     /// # Panics
     ///
     /// If the starting point is greater than the end point of if the end point
-    /// is greater than the length of the map.
+    /// is greater than the length of the map. A bound of `usize::MAX`, that
+    /// would have to be shifted by one, is out of bounds as well.
     ///
     /// # Examples
     ///
@@ -421,21 +849,29 @@ where
     /// # Ok::<_, array_map::CapacityError>(())
     /// ```
     ///
+    /// The entries are removed from the map when the iterator is created and
+    /// none of them are hashed, so collecting the iterator into another map
+    /// hashes every key exactly once.
+    ///
     /// # Complexity
     ///
-    /// O(n^2)
+    /// O(n)
     pub fn drain_range<R: RangeBounds<usize>>(&mut self, range: R) -> DrainRange<'_, K, V, B, N> {
         let start = {
             match range.start_bound() {
                 Bound::Included(index) => *index,
-                Bound::Excluded(index) => *index + 1,
+                Bound::Excluded(index) => index
+                    .checked_add(1)
+                    .expect("attempted to index slice from after maximum usize"),
                 Bound::Unbounded => 0,
             }
         };
 
         let end = {
             match range.end_bound() {
-                Bound::Included(index) => *index + 1,
+                Bound::Included(index) => index
+                    .checked_add(1)
+                    .expect("attempted to index slice up to maximum usize"),
                 Bound::Excluded(index) => *index,
                 Bound::Unbounded => self.len(),
             }
@@ -449,15 +885,963 @@ where
             panic!("end of range is out of bounds");
         }
 
-        DrainRange::new(self, start..end)
+        // SAFETY: the range has been checked above
+        DrainRange::new(unsafe { self.table.drain_range(start..end) })
     }
-}
 
-impl<K, V, const N: usize, B: BuildHasher> Index<usize> for IndexMap<K, V, N, B>
-where
-    K: Hash + Eq,
-{
-    type Output = V;
+    /// Splits the map into two at the index, moving the entries in
+    /// `[at, len)` into a new map and keeping those in `[0, at)`.
+    ///
+    /// The new map uses a clone of the [`BuildHasher`], so the keys do not
+    /// have to be hashed again.
+    ///
+    /// # Errors
+    ///
+    /// If the moved entries do not fit into the new map, a [`CapacityError`] is
+    /// returned and the map is left unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::ext::IteratorExt;
+    /// use array_map::{index_map, IndexMap};
+    ///
+    /// let mut map: IndexMap<&str, u32, 7> = index_map! {
+    ///     @infer,
+    ///     "a" => 1,
+    ///     "b" => 2,
+    ///     "c" => 3,
+    ///     "d" => 4,
+    /// }?;
+    ///
+    /// assert!(map.try_split_off::<1>(2).is_err());
+    ///
+    /// let tail: IndexMap<&str, u32, 3> = map.try_split_off(2)?;
+    /// assert_eq!(map.keys().try_collect(), Ok([&"a", &"b"]));
+    /// assert_eq!(tail.keys().try_collect(), Ok([&"c", &"d"]));
+    /// assert_eq!(tail.get("d"), Some(&4));
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// O(n)
+    #[track_caller]
+    pub fn try_split_off<const M: usize>(
+        &mut self,
+        at: usize,
+    ) -> Result<IndexMap<K, V, M, B>, CapacityError>
+    where
+        B: Clone,
+    {
+        let len = self.len();
+        assert!(
+            at <= len,
+            "`at` split index (is {at}) should be <= len (is {len})"
+        );
+
+        // SAFETY: the split index has been checked above
+        let table = unsafe { self.table.try_split_off(at)? };
+
+        Ok(ArrayMapFacade::from_parts(table, self.build_hasher.clone()))
+    }
+
+    /// Splits the map into two at the index, moving the entries in
+    /// `[at, len)` into a new map and keeping those in `[0, at)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len` or if the moved entries do not fit into the new
+    /// map. Use [`IndexMap::try_split_off`] to handle the latter case.
+    #[cfg(feature = "panic")]
+    #[track_caller]
+    pub fn split_off<const M: usize>(&mut self, at: usize) -> IndexMap<K, V, M, B>
+    where
+        B: Clone,
+    {
+        self.try_split_off(at)
+            .unwrap_or_else(|_| utils::capacity_overflow(M))
+    }
+
+    /// Moves all entries of `other` into this map, leaving `other` empty.
+    ///
+    /// The new entries are appended in the order of `other`. If a key is
+    /// already present, its value is replaced and the entry keeps its
+    /// position, like it would with [`IndexMap::try_insert_full`].
+    ///
+    /// Every key of `other` is hashed once.
+    ///
+    /// # Errors
+    ///
+    /// If the new entries do not fit into the map, a [`CapacityError`] is
+    /// returned and both maps are left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::ext::IteratorExt;
+    /// use array_map::{index_map, IndexMap};
+    ///
+    /// let mut map: IndexMap<&str, u32, 5> = index_map! {
+    ///     @infer,
+    ///     "a" => 1,
+    ///     "b" => 2,
+    /// }?;
+    /// let mut other: IndexMap<&str, u32, 3> = index_map! {
+    ///     @infer,
+    ///     "c" => 3,
+    ///     "a" => 4,
+    /// }?;
+    ///
+    /// map.try_append(&mut other)?;
+    /// assert!(other.is_empty());
+    /// assert_eq!(
+    ///     map.iter().try_collect(),
+    ///     Ok([(&"a", &4), (&"b", &2), (&"c", &3)])
+    /// );
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// O(m), where m is the length of `other`
+    pub fn try_append<F, const M: usize>(
+        &mut self,
+        other: &mut IndexMap<K, V, M, B, F>,
+    ) -> Result<(), CapacityError>
+    where
+        F: VecStorage<(K, V), M>,
+    {
+        let mut hashes = [0; M];
+        let mut new_keys = 0;
+
+        for ((key, _), hash) in other.iter().zip(&mut hashes) {
+            *hash = utils::make_hash::<K, K, B>(&self.build_hasher, key);
+
+            if self.table.find(*hash, |(k, _)| k.eq(key)).is_none() {
+                new_keys += 1;
+            }
+        }
+
+        if self.len() + new_keys > self.capacity() {
+            return Err(CapacityError::new(self.capacity(), self.len() + new_keys));
+        }
+
+        for ((key, value), hash) in other.table.drain().zip(hashes) {
+            if let Some(ident) = self.table.find(hash, |(k, _)| k.eq(&key)) {
+                // SAFETY: the ident has just been returned by find
+                let (_, old_value) = unsafe { self.table.get_unchecked_mut(ident) };
+                *old_value = value;
+                continue;
+            }
+
+            let result =
+                self.table
+                    .try_insert(hash, (key, value), utils::key_hasher(&self.build_hasher));
+
+            if result.is_err() {
+                unreachable_unchecked!("there must be free space in the table");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Moves all entries of `other` into this map, leaving `other` empty.
+    ///
+    /// See [`IndexMap::try_append`] for more.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new entries do not fit into the map. Use
+    /// [`IndexMap::try_append`] to handle this case.
+    #[cfg(feature = "panic")]
+    #[track_caller]
+    pub fn append<F, const M: usize>(&mut self, other: &mut IndexMap<K, V, M, B, F>)
+    where
+        F: VecStorage<(K, V), M>,
+    {
+        let capacity = self.capacity();
+        self.try_append(other)
+            .unwrap_or_else(|_| utils::capacity_overflow(capacity));
+    }
+
+    /// Inserts the key-value pair at the `index`, shifting all entries after it
+    /// to the right.
+    ///
+    /// If the key is already present, its value is replaced and the entry is
+    /// moved to the `index`.
+    ///
+    /// The final index of the entry and the old value (if present) are
+    /// returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CapacityError`] if the key is not present and the map is
+    /// already full.
+    ///
+    /// # Panics
+    ///
+    /// If the key is present and the `index` is not less than the length of
+    /// the map, or if the key is not present and the `index` is greater than
+    /// the length of the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::ext::IteratorExt;
+    /// use array_map::{index_map, IndexMap};
+    ///
+    /// let mut map: IndexMap<&str, u32, 5> = index_map! {
+    ///     @infer,
+    ///     "a" => 1,
+    ///     "b" => 2,
+    ///     "c" => 3,
+    /// }?;
+    ///
+    /// assert_eq!(map.try_shift_insert(1, "d", 4)?, (1, None));
+    /// assert_eq!(map.try_shift_insert(3, "a", 0)?, (3, Some(1)));
+    ///
+    /// assert_eq!(
+    ///     map.into_iter().try_collect::<[_; 4]>(),
+    ///     Ok([("d", 4), ("b", 2), ("c", 3), ("a", 0)])
+    /// );
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// O(n)
+    #[track_caller]
+    pub fn try_shift_insert(
+        &mut self,
+        index: usize,
+        key: K,
+        value: V,
+    ) -> Result<(usize, Option<V>), CapacityError> {
+        self.try_move_insert(key, value, |current, len| {
+            let end = if current.is_some() { len } else { len + 1 };
+            assert!(
+                index < end,
+                "index out of bounds: the len is {len} but the index is {index}"
+            );
+
+            index
+        })
+    }
+
+    /// Inserts the key-value pair before the entry at the `index`, shifting all
+    /// entries after it to the right.
+    ///
+    /// If the key is already present, its value is replaced and the entry is
+    /// moved before the entry at the `index`. If the entry has been in front
+    /// of the `index`, it will end up at `index - 1`.
+    ///
+    /// An `index` equal to the length of the map moves the entry to the end.
+    ///
+    /// The final index of the entry and the old value (if present) are
+    /// returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CapacityError`] if the key is not present and the map is
+    /// already full.
+    ///
+    /// # Panics
+    ///
+    /// If the `index` is greater than the length of the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::ext::IteratorExt;
+    /// use array_map::{index_map, IndexMap};
+    ///
+    /// let mut map: IndexMap<&str, u32, 5> = index_map! {
+    ///     @infer,
+    ///     "a" => 1,
+    ///     "b" => 2,
+    ///     "c" => 3,
+    /// }?;
+    ///
+    /// assert_eq!(map.try_insert_before(2, "a", 0)?, (1, Some(1)));
+    /// assert_eq!(map.try_insert_before(3, "d", 4)?, (3, None));
+    ///
+    /// assert_eq!(
+    ///     map.into_iter().try_collect::<[_; 4]>(),
+    ///     Ok([("b", 2), ("a", 0), ("c", 3), ("d", 4)])
+    /// );
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// O(n)
+    #[track_caller]
+    pub fn try_insert_before(
+        &mut self,
+        index: usize,
+        key: K,
+        value: V,
+    ) -> Result<(usize, Option<V>), CapacityError> {
+        let len = self.len();
+        assert!(
+            index <= len,
+            "index out of bounds: the len is {len} but the index is {index}"
+        );
+
+        self.try_move_insert(key, value, |current, _| match current {
+            Some(current) if current < index => index - 1,
+            _ => index,
+        })
+    }
+
+    /// Inserts the key-value pair at its sorted position, which is determined
+    /// by a binary search of the keys.
+    ///
+    /// If the key is already present, its value is replaced and the entry is
+    /// moved to the sorted position.
+    ///
+    /// If the map is not sorted by its keys, the position of the entry is
+    /// unspecified.
+    ///
+    /// The final index of the entry and the old value (if present) are
+    /// returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CapacityError`] if the key is not present and the map is
+    /// already full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::ext::IteratorExt;
+    /// use array_map::{index_map, IndexMap};
+    ///
+    /// let mut map: IndexMap<u32, &str, 5> = index_map! {
+    ///     @infer,
+    ///     1 => "a",
+    ///     3 => "c",
+    /// }?;
+    ///
+    /// assert_eq!(map.try_insert_sorted(2, "b")?, (1, None));
+    /// assert_eq!(map.try_insert_sorted(3, "C")?, (2, Some("c")));
+    ///
+    /// assert_eq!(
+    ///     map.into_iter().try_collect::<[_; 3]>(),
+    ///     Ok([(1, "a"), (2, "b"), (3, "C")])
+    /// );
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// O(n)
+    pub fn try_insert_sorted(
+        &mut self,
+        key: K,
+        value: V,
+    ) -> Result<(usize, Option<V>), CapacityError>
+    where
+        K: Ord,
+    {
+        let index = match self.table.binary_search_by(|(other, _)| other.cmp(&key)) {
+            Ok(index) | Err(index) => index,
+        };
+
+        self.try_insert_before(index, key, value)
+    }
+
+    /// Inserts the key-value pair at the `index`, shifting all entries after it
+    /// to the right.
+    ///
+    /// See [`IndexMap::try_shift_insert`] for more.
+    ///
+    /// # Panics
+    ///
+    /// If the key is not present and the map is already full, or if the index
+    /// is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::IndexMap;
+    ///
+    /// let mut map: IndexMap<&str, u32, 5> = IndexMap::new();
+    /// map.insert("a", 1);
+    ///
+    /// assert_eq!(map.shift_insert(0, "b", 2), (0, None));
+    /// assert_eq!(map.get_entry_at(0), Some((&"b", &2)));
+    /// ```
+    #[cfg(feature = "panic")]
+    #[track_caller]
+    pub fn shift_insert(&mut self, index: usize, key: K, value: V) -> (usize, Option<V>) {
+        let capacity = self.capacity();
+        self.try_shift_insert(index, key, value)
+            .unwrap_or_else(|_| utils::capacity_overflow(capacity))
+    }
+
+    /// Inserts the key-value pair before the entry at the `index`.
+    ///
+    /// See [`IndexMap::try_insert_before`] for more.
+    ///
+    /// # Panics
+    ///
+    /// If the key is not present and the map is already full, or if the index
+    /// is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::IndexMap;
+    ///
+    /// let mut map: IndexMap<&str, u32, 5> = IndexMap::new();
+    /// map.insert("a", 1);
+    /// map.insert("b", 2);
+    ///
+    /// assert_eq!(map.insert_before(0, "b", 0), (0, Some(2)));
+    /// assert_eq!(map.get_entry_at(0), Some((&"b", &0)));
+    /// ```
+    #[cfg(feature = "panic")]
+    #[track_caller]
+    pub fn insert_before(&mut self, index: usize, key: K, value: V) -> (usize, Option<V>) {
+        let capacity = self.capacity();
+        self.try_insert_before(index, key, value)
+            .unwrap_or_else(|_| utils::capacity_overflow(capacity))
+    }
+
+    /// Inserts the key-value pair at its sorted position.
+    ///
+    /// See [`IndexMap::try_insert_sorted`] for more.
+    ///
+    /// # Panics
+    ///
+    /// If the key is not present and the map is already full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::IndexMap;
+    ///
+    /// let mut map: IndexMap<u32, &str, 5> = IndexMap::new();
+    /// map.insert(1, "a");
+    /// map.insert(3, "c");
+    ///
+    /// assert_eq!(map.insert_sorted(2, "b"), (1, None));
+    /// ```
+    #[cfg(feature = "panic")]
+    #[track_caller]
+    pub fn insert_sorted(&mut self, key: K, value: V) -> (usize, Option<V>)
+    where
+        K: Ord,
+    {
+        let capacity = self.capacity();
+        self.try_insert_sorted(key, value)
+            .unwrap_or_else(|_| utils::capacity_overflow(capacity))
+    }
+
+    /// Inserts the entry or replaces the value of the present entry and moves
+    /// it to the index returned by `to`.
+    ///
+    /// `to` is called with the current index of the entry (if the key is
+    /// present) and the length of the map before the insertion.
+    #[track_caller]
+    fn try_move_insert(
+        &mut self,
+        key: K,
+        value: V,
+        to: impl FnOnce(Option<usize>, usize) -> usize,
+    ) -> Result<(usize, Option<V>), CapacityError> {
+        let len = self.len();
+        let hash = utils::make_hash::<K, K, B>(&self.build_hasher, &key);
+
+        if let Some(ident) = self.table.find(hash, |(k, _)| k.eq(&key)) {
+            // SAFETY: the ident has just been returned by find
+            let current = unsafe { self.table.index_from_ident(ident).index() };
+            let index = to(Some(current), len);
+
+            // SAFETY: the ident is still valid, because the table has not been modified
+            let (_, old_value) = unsafe { self.table.get_unchecked_mut(ident) };
+            let old_value = mem::replace(old_value, value);
+            if index != current {
+                self.table.move_index(current, index);
+            }
+
+            return Ok((index, Some(old_value)));
+        }
+
+        if len == self.capacity() {
+            return Err(CapacityError::new(self.capacity(), len + 1));
+        }
+
+        let index = to(None, len);
+        let result =
+            self.table
+                .try_insert(hash, (key, value), utils::key_hasher(&self.build_hasher));
+
+        if result.is_err() {
+            unreachable_unchecked!("there must be free space in the table");
+        }
+
+        if index != len {
+            self.table.move_index(len, index);
+        }
+
+        Ok((index, None))
+    }
+
+    /// Sorts the entries of the map by their keys.
+    ///
+    /// The sort is stable and done in-place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::ext::IteratorExt;
+    /// use array_map::{index_map, IndexMap};
+    ///
+    /// let mut map: IndexMap<&str, u32, 5> = index_map! {
+    ///     @infer,
+    ///     "c" => 3,
+    ///     "a" => 1,
+    ///     "b" => 2,
+    /// }?;
+    ///
+    /// map.sort_keys();
+    ///
+    /// assert_eq!(
+    ///     map.into_iter().try_collect::<[_; 3]>(),
+    ///     Ok([("a", 1), ("b", 2), ("c", 3)])
+    /// );
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// O(n * log(n))
+    pub fn sort_keys(&mut self)
+    where
+        K: Ord,
+    {
+        self.table.sort_by(|(a, _), (b, _)| a.cmp(b));
+    }
+
+    /// Sorts the entries of the map with the comparator function.
+    ///
+    /// The comparator gets the key and the value of the two entries that are
+    /// compared. The sort is stable and done in-place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::ext::IteratorExt;
+    /// use array_map::{index_map, IndexMap};
+    ///
+    /// let mut map: IndexMap<&str, u32, 7> = index_map! {
+    ///     @infer,
+    ///     "a" => 2,
+    ///     "b" => 1,
+    ///     "c" => 2,
+    ///     "d" => 0,
+    /// }?;
+    ///
+    /// // sort by the values in descending order
+    /// map.sort_by(|_, v1, _, v2| v2.cmp(v1));
+    ///
+    /// assert_eq!(
+    ///     map.into_iter().try_collect::<[_; 4]>(),
+    ///     Ok([("a", 2), ("c", 2), ("b", 1), ("d", 0)])
+    /// );
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// O(n * log(n))
+    pub fn sort_by<F>(&mut self, mut cmp: F)
+    where
+        F: FnMut(&K, &V, &K, &V) -> Ordering,
+    {
+        self.table.sort_by(|(k1, v1), (k2, v2)| cmp(k1, v1, k2, v2));
+    }
+
+    /// Sorts the entries of the map with the comparator function, but might
+    /// not preserve the order of equal entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::ext::IteratorExt;
+    /// use array_map::{index_map, IndexMap};
+    ///
+    /// let mut map: IndexMap<u32, &str, 5> = index_map! {
+    ///     @infer,
+    ///     3 => "c",
+    ///     1 => "a",
+    ///     2 => "b",
+    /// }?;
+    ///
+    /// map.sort_unstable_by(|k1, _, k2, _| k1.cmp(k2));
+    ///
+    /// assert_eq!(
+    ///     map.into_iter().try_collect::<[_; 3]>(),
+    ///     Ok([(1, "a"), (2, "b"), (3, "c")])
+    /// );
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// O(n * log(n))
+    pub fn sort_unstable_by<F>(&mut self, mut cmp: F)
+    where
+        F: FnMut(&K, &V, &K, &V) -> Ordering,
+    {
+        self.table
+            .sort_unstable_by(|(k1, v1), (k2, v2)| cmp(k1, v1, k2, v2));
+    }
+
+    /// Sorts the entries of the map with a key extraction function.
+    ///
+    /// The function is called exactly once per entry, which is useful if it is
+    /// expensive. The sort is stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::ext::IteratorExt;
+    /// use array_map::{index_map, IndexMap};
+    ///
+    /// let mut map: IndexMap<&str, i32, 5> = index_map! {
+    ///     @infer,
+    ///     "a" => -3,
+    ///     "b" => 1,
+    ///     "c" => -2,
+    /// }?;
+    ///
+    /// map.sort_by_cached_key(|_, v| v.abs());
+    ///
+    /// assert_eq!(
+    ///     map.into_iter().try_collect::<[_; 3]>(),
+    ///     Ok([("b", 1), ("c", -2), ("a", -3)])
+    /// );
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// O(n * log(n))
+    pub fn sort_by_cached_key<T, F>(&mut self, mut f: F)
+    where
+        T: Ord,
+        F: FnMut(&K, &V) -> T,
+    {
+        self.table.sort_by_cached_key(|(k, v)| f(k, v));
+    }
+
+    /// Reverses the order of the entries in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::ext::IteratorExt;
+    /// use array_map::{index_map, IndexMap};
+    ///
+    /// let mut map: IndexMap<u32, &str, 5> = index_map! {
+    ///     @infer,
+    ///     1 => "a",
+    ///     2 => "b",
+    ///     3 => "c",
+    /// }?;
+    ///
+    /// map.reverse();
+    ///
+    /// assert_eq!(
+    ///     map.into_iter().try_collect::<[_; 3]>(),
+    ///     Ok([(3, "c"), (2, "b"), (1, "a")])
+    /// );
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// O(n)
+    pub fn reverse(&mut self) {
+        self.table.reverse();
+    }
+
+    /// Rotates the entries of the map in-place, so that the entry at `mid`
+    /// becomes the first entry.
+    ///
+    /// # Panics
+    ///
+    /// If `mid` is greater than the length of the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::ext::IteratorExt;
+    /// use array_map::{index_map, IndexMap};
+    ///
+    /// let mut map: IndexMap<u32, &str, 5> = index_map! {
+    ///     @infer,
+    ///     1 => "a",
+    ///     2 => "b",
+    ///     3 => "c",
+    /// }?;
+    ///
+    /// map.rotate_left(1);
+    ///
+    /// assert_eq!(
+    ///     map.into_iter().try_collect::<[_; 3]>(),
+    ///     Ok([(2, "b"), (3, "c"), (1, "a")])
+    /// );
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// O(n)
+    pub fn rotate_left(&mut self, mid: usize) {
+        self.table.rotate_left(mid);
+    }
+
+    /// Rotates the entries of the map in-place, so that the last `k` entries
+    /// are moved to the front.
+    ///
+    /// # Panics
+    ///
+    /// If `k` is greater than the length of the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::ext::IteratorExt;
+    /// use array_map::{index_map, IndexMap};
+    ///
+    /// let mut map: IndexMap<u32, &str, 5> = index_map! {
+    ///     @infer,
+    ///     1 => "a",
+    ///     2 => "b",
+    ///     3 => "c",
+    /// }?;
+    ///
+    /// map.rotate_right(1);
+    ///
+    /// assert_eq!(
+    ///     map.into_iter().try_collect::<[_; 3]>(),
+    ///     Ok([(3, "c"), (1, "a"), (2, "b")])
+    /// );
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// O(n)
+    pub fn rotate_right(&mut self, k: usize) {
+        self.table.rotate_right(k);
+    }
+
+    /// Moves the entry at the index `from` to the index `to`, shifting the
+    /// entries in between.
+    ///
+    /// # Panics
+    ///
+    /// If either `from` or `to` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::ext::IteratorExt;
+    /// use array_map::{index_map, IndexMap};
+    ///
+    /// let mut map: IndexMap<u32, &str, 5> = index_map! {
+    ///     @infer,
+    ///     1 => "a",
+    ///     2 => "b",
+    ///     3 => "c",
+    ///     4 => "d",
+    /// }?;
+    ///
+    /// map.move_index(0, 2);
+    /// assert_eq!(map.keys().try_collect::<[_; 4]>(), Ok([&2, &3, &1, &4]));
+    ///
+    /// map.move_index(3, 0);
+    /// assert_eq!(map.keys().try_collect::<[_; 4]>(), Ok([&4, &2, &3, &1]));
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// O(n)
+    pub fn move_index(&mut self, from: usize, to: usize) {
+        assert!(
+            from < self.len() && to < self.len(),
+            "index out of bounds: the len is {} but the indices are {from} and {to}",
+            self.len()
+        );
+
+        self.table.move_index(from, to);
+    }
+
+    /// Retains only the entries specified by the predicate, passing a mutable
+    /// reference to the key and the value.
+    ///
+    /// The entries are visited in their order and the retained entries keep
+    /// their relative order. The map is compacted in a single pass.
+    ///
+    /// The keys may be modified, but they must remain unique in the map.
+    /// The map is rehashed afterwards, so the hash of a key may change.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::ext::IteratorExt;
+    /// use array_map::{index_map, IndexMap};
+    ///
+    /// let mut map: IndexMap<u32, &str, 5> = index_map! {
+    ///     @infer,
+    ///     1 => "a",
+    ///     2 => "b",
+    ///     3 => "c",
+    ///     4 => "d",
+    /// }?;
+    ///
+    /// map.retain_mut(|k, _| {
+    ///     *k *= 10;
+    ///     *k != 20
+    /// });
+    ///
+    /// assert_eq!(
+    ///     map.into_iter().try_collect::<[_; 3]>(),
+    ///     Ok([(10, "a"), (30, "c"), (40, "d")])
+    /// );
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// O(n)
+    pub fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut K, &mut V) -> bool,
+    {
+        self.table.retain(
+            |(key, value)| f(key, value),
+            utils::key_hasher(&self.build_hasher),
+        );
+    }
+
+    /// Creates an iterator which removes and yields all entries for which the
+    /// closure returns `true`.
+    ///
+    /// The entries are visited in their order and the retained entries keep
+    /// their relative order.
+    ///
+    /// If the iterator is only partially consumed or not consumed at all, the
+    /// remaining entries are not subjected to the closure and are retained.
+    /// If the closure panics, the entry it has been called with is retained.
+    ///
+    /// Unlike [`ArrayMapFacade::drain_filter`], this compacts the map in a
+    /// single pass and only rebuilds the indices once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::ext::IteratorExt;
+    /// use array_map::{index_map, IndexMap};
+    ///
+    /// let mut map: IndexMap<u32, &str, 7> = index_map! {
+    ///     @infer,
+    ///     1 => "a",
+    ///     2 => "b",
+    ///     3 => "c",
+    ///     4 => "d",
+    ///     5 => "e",
+    /// }?;
+    ///
+    /// assert_eq!(
+    ///     map.extract_if(|k, _| k % 2 == 0).try_collect::<[_; 2]>(),
+    ///     Ok([(2, "b"), (4, "d")])
+    /// );
+    ///
+    /// assert_eq!(
+    ///     map.into_iter().try_collect::<[_; 3]>(),
+    ///     Ok([(1, "a"), (3, "c"), (5, "e")])
+    /// );
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// O(n)
+    pub fn extract_if<F>(&mut self, f: F) -> ExtractIf<'_, K, V, F, B, N, E>
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        ExtractIf::new(f, &mut self.table, &self.build_hasher)
+    }
+
+    /// Feeds the entries in their order into the [`Hasher`].
+    ///
+    /// The [`Hash`] implementation of the map ignores the order of the
+    /// entries, like its [`PartialEq`] implementation. This method can be used
+    /// where the order is relevant, so maps with the same entries in a
+    /// different order will most likely produce different hashes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::hash::{BuildHasher, Hash, Hasher};
+    ///
+    /// use array_map::{DefaultHashBuilder, IndexMap};
+    ///
+    /// let mut a: IndexMap<&str, u32, 5> = IndexMap::new();
+    /// a.try_insert("a", 1)?;
+    /// a.try_insert("b", 2)?;
+    ///
+    /// let mut b: IndexMap<&str, u32, 5> = IndexMap::new();
+    /// b.try_insert("b", 2)?;
+    /// b.try_insert("a", 1)?;
+    ///
+    /// let build_hasher = DefaultHashBuilder::new();
+    /// let hash = |map: &IndexMap<&str, u32, 5>, ordered: bool| {
+    ///     let mut hasher = build_hasher.build_hasher();
+    ///     if ordered {
+    ///         map.hash_ordered(&mut hasher);
+    ///     } else {
+    ///         map.hash(&mut hasher);
+    ///     }
+    ///     hasher.finish()
+    /// };
+    ///
+    /// assert_eq!(hash(&a, false), hash(&b, false));
+    /// assert_ne!(hash(&a, true), hash(&b, true));
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    pub fn hash_ordered<H: Hasher>(&self, state: &mut H)
+    where
+        V: Hash,
+    {
+        state.write_usize(self.len());
+
+        for entry in self {
+            entry.hash(state);
+        }
+    }
+}
+
+impl<K, V, const N: usize, B: BuildHasher, E> Index<usize> for IndexMap<K, V, N, B, E>
+where
+    K: Hash + Eq,
+    E: VecStorage<(K, V), N>,
+{
+    type Output = V;
 
     fn index(&self, index: usize) -> &Self::Output {
         self.get_entry_at(index)
@@ -475,10 +1859,13 @@ impl fmt::Display for IndexOutOfBoundsError {
     }
 }
 
+impl core::error::Error for IndexOutOfBoundsError {}
+
 #[cfg(all(test, feature = "ahash"))]
 mod tests {
     use super::*;
 
+    use crate::array_vec::CopyArrayVec;
     use crate::ext::IteratorExt;
     use crate::index_map;
     use crate::raw::{ArrayTable, TableIndex};
@@ -554,12 +1941,12 @@ mod tests {
                 // indices
                 ArrayTable::from_array([
                     // HasHash(0, 0)
-                    Some(unsafe { TableIndex::new(1) }),
+                    Some((0, unsafe { TableIndex::new(1) })),
                     None,
                     None,
                     None,
                     //
-                    Some(unsafe { TableIndex::new(0) }),
+                    Some((4, unsafe { TableIndex::new(0) })),
                 ]),
                 // entries
                 [(HasHash(4, 0), 1), (HasHash(0, 0), 0)]
@@ -580,7 +1967,7 @@ mod tests {
                 // indices
                 ArrayTable::from_array([
                     // HasHash(0, 0)
-                    Some(unsafe { TableIndex::new(0) }),
+                    Some((0, unsafe { TableIndex::new(0) })),
                     None,
                     None,
                     None,
@@ -613,8 +2000,8 @@ mod tests {
                 ArrayTable::from_array([
                     None,
                     None,
-                    Some(unsafe { TableIndex::new(1) }),
-                    Some(unsafe { TableIndex::new(0) }),
+                    Some((2, unsafe { TableIndex::new(1) })),
+                    Some((3, unsafe { TableIndex::new(0) })),
                     None,
                 ]),
                 // entries
@@ -640,7 +2027,7 @@ mod tests {
                 ArrayTable::from_array([
                     None,
                     None,
-                    Some(unsafe { TableIndex::new(0) }),
+                    Some((2, unsafe { TableIndex::new(0) })),
                     None,
                     //
                     None,
@@ -772,4 +2159,574 @@ mod tests {
             Ok([None, None, None, None])
         );
     }
+
+    #[test]
+    fn test_shift_remove_front_and_truncate() {
+        let mut map: IndexMap<u32, u32, 17> = IndexMap::new();
+        for i in 0..16 {
+            map.try_insert(i, i * 2).unwrap();
+        }
+
+        for removed in 0..4 {
+            assert_eq!(map.shift_remove_index(0), Some((removed, removed * 2)));
+
+            for (index, key) in (removed + 1..16).enumerate() {
+                assert_eq!(map.get(&key), Some(&(key * 2)));
+                assert_eq!(map.get_entry_at(index), Some((&key, &(key * 2))));
+            }
+        }
+
+        map.truncate(5);
+        assert_eq!(map.len(), 5);
+        assert_eq!(map.keys().try_collect::<[_; 5]>(), Ok([&4, &5, &6, &7, &8]));
+        assert_eq!(map.get(&9), None);
+        assert_eq!(map.get(&8), Some(&16));
+
+        map.try_insert(9, 0).unwrap();
+        assert_eq!(map.get_entry_at(5), Some((&9, &0)));
+    }
+
+    #[test]
+    fn test_sort_and_reorder_keep_lookups() {
+        let mut map: IndexMap<u32, u32, 11> = IndexMap::new();
+        for key in [7, 3, 9, 1, 5, 8, 2] {
+            map.try_insert(key, key * 10).unwrap();
+        }
+
+        let assert_lookups = |map: &IndexMap<u32, u32, 11>, expected: [u32; 7]| {
+            assert_eq!(map.keys().copied().try_collect::<[_; 7]>(), Ok(expected));
+            for (index, key) in expected.into_iter().enumerate() {
+                assert_eq!(map.get(&key), Some(&(key * 10)));
+                assert_eq!(map.get_entry_at(index), Some((&key, &(key * 10))));
+            }
+        };
+
+        map.sort_keys();
+        assert_lookups(&map, [1, 2, 3, 5, 7, 8, 9]);
+
+        map.sort_unstable_by(|k1, _, k2, _| k2.cmp(k1));
+        assert_lookups(&map, [9, 8, 7, 5, 3, 2, 1]);
+
+        // stable: the odd keys keep their relative order
+        map.sort_by(|k1, _, k2, _| (k1 % 2).cmp(&(k2 % 2)));
+        assert_lookups(&map, [8, 2, 9, 7, 5, 3, 1]);
+
+        map.sort_by_cached_key(|k, _| k % 3);
+        assert_lookups(&map, [9, 3, 7, 1, 8, 2, 5]);
+
+        map.reverse();
+        assert_lookups(&map, [5, 2, 8, 1, 7, 3, 9]);
+
+        map.rotate_left(2);
+        assert_lookups(&map, [8, 1, 7, 3, 9, 5, 2]);
+
+        map.rotate_right(3);
+        assert_lookups(&map, [9, 5, 2, 8, 1, 7, 3]);
+
+        map.move_index(1, 5);
+        assert_lookups(&map, [9, 2, 8, 1, 7, 5, 3]);
+
+        map.move_index(6, 0);
+        assert_lookups(&map, [3, 9, 2, 8, 1, 7, 5]);
+
+        map.move_index(4, 4);
+        assert_lookups(&map, [3, 9, 2, 8, 1, 7, 5]);
+
+        assert_eq!(map.shift_remove_index(0), Some((3, 30)));
+        map.try_insert(4, 40).unwrap();
+        assert_eq!(map.get_entry_at(6), Some((&4, &40)));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempted to index slice from after maximum usize")]
+    fn test_drain_range_excluded_start_overflow() {
+        let mut map: IndexMap<u32, u32, 5> = IndexMap::new();
+        map.drain_range((Bound::Excluded(usize::MAX), Bound::Unbounded));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempted to index slice up to maximum usize")]
+    fn test_drain_range_included_end_overflow() {
+        let mut map: IndexMap<u32, u32, 5> = IndexMap::new();
+        map.drain_range(..=usize::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn test_move_index_out_of_bounds() {
+        let mut map: IndexMap<u32, u32, 5> = IndexMap::new();
+        map.try_insert(1, 1).unwrap();
+
+        map.move_index(0, 1);
+    }
+
+    #[test]
+    fn test_positional_insert() {
+        let mut map: IndexMap<u32, u32, 5> = IndexMap::new();
+
+        assert_eq!(map.try_shift_insert(0, 3, 30), Ok((0, None)));
+        assert_eq!(map.try_shift_insert(0, 1, 10), Ok((0, None)));
+        assert_eq!(map.try_insert_before(2, 4, 40), Ok((2, None)));
+        assert_eq!(map.try_insert_before(2, 2, 20), Ok((2, None)));
+        assert_eq!(
+            map.iter().try_collect::<[_; 4]>(),
+            Ok([(&1, &10), (&3, &30), (&2, &20), (&4, &40)])
+        );
+
+        // moving an existing entry to the back and to the front
+        assert_eq!(map.try_insert_before(4, 3, 31), Ok((3, Some(30))));
+        assert_eq!(map.try_shift_insert(0, 4, 41), Ok((0, Some(40))));
+        assert_eq!(
+            map.iter().try_collect::<[_; 4]>(),
+            Ok([(&4, &41), (&1, &10), (&2, &20), (&3, &31)])
+        );
+
+        for (index, key) in [4, 1, 2, 3].into_iter().enumerate() {
+            assert_eq!(map.get_entry_at(index).map(|(k, _)| *k), Some(key));
+            assert!(map.contains_key(&key));
+        }
+
+        assert_eq!(map.try_shift_insert(4, 0, 0), Ok((4, None)));
+        assert_eq!(
+            map.try_shift_insert(0, 5, 50),
+            Err(CapacityError::new(5, 6))
+        );
+        // present keys can still be moved in a full map
+        assert_eq!(map.try_shift_insert(4, 4, 42), Ok((4, Some(41))));
+        assert_eq!(map.get_entry_at(4), Some((&4, &42)));
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn test_shift_insert_present_key_out_of_bounds() {
+        let mut map: IndexMap<u32, u32, 5> = IndexMap::new();
+        map.try_insert(1, 1).unwrap();
+
+        // the present key can only be moved to index 0
+        let _ = map.try_shift_insert(1, 1, 2);
+    }
+
+    #[test]
+    fn test_insert_sorted() {
+        let mut map: IndexMap<u32, &str, 7> = IndexMap::new();
+
+        for key in [5, 1, 4, 2, 3] {
+            map.try_insert_sorted(key, "a").unwrap();
+        }
+        assert_eq!(map.try_insert_sorted(4, "b"), Ok((3, Some("a"))));
+
+        assert_eq!(
+            map.iter().try_collect::<[_; 5]>(),
+            Ok([(&1, &"a"), (&2, &"a"), (&3, &"a"), (&4, &"b"), (&5, &"a")])
+        );
+    }
+
+    #[test]
+    fn test_vacant_entry_shift_insert() {
+        use crate::map::Entry;
+
+        let mut map: IndexMap<u32, u32, 5> = IndexMap::new();
+        map.try_insert(1, 1).unwrap();
+        map.try_insert(2, 2).unwrap();
+
+        let Ok(Entry::Vacant(entry)) = map.entry(0) else {
+            panic!("the entry should be vacant");
+        };
+        *entry.shift_insert(0, 5) += 1;
+
+        assert_eq!(
+            map.iter().try_collect::<[_; 3]>(),
+            Ok([(&0, &6), (&1, &1), (&2, &2)])
+        );
+        assert_eq!(map.get(&0), Some(&6));
+    }
+
+    #[test]
+    fn test_index_lookups() {
+        use crate::map::Entry;
+
+        let mut map: IndexMap<&str, u32, 5> = IndexMap::new();
+        assert_eq!(map.first(), None);
+        assert_eq!(map.last_mut(), None);
+
+        assert_eq!(map.try_insert_full("a", 1), Ok((0, None)));
+        assert_eq!(map.try_insert_full("b", 2), Ok((1, None)));
+        assert_eq!(map.try_insert_full("c", 3), Ok((2, None)));
+        assert_eq!(map.try_insert_full("b", 4), Ok((1, Some(2))));
+
+        assert_eq!(map.shift_remove_entry("a"), Some(("a", 1)));
+        assert_eq!(map.get_index_of("c"), Some(1));
+        assert_eq!(map.get_full("b"), Some((0, &"b", &4)));
+        assert_eq!(map.get_full_mut("c"), Some((1, &"c", &mut 3)));
+        assert_eq!(map.get_full("a"), None);
+
+        assert_eq!(map.first(), Some((&"b", &4)));
+        assert_eq!(map.last(), Some((&"c", &3)));
+
+        match map.entry("c") {
+            Ok(Entry::Occupied(entry)) => assert_eq!(entry.index(), 1),
+            _ => panic!("the entry should be occupied"),
+        }
+        match map.entry("d") {
+            Ok(Entry::Vacant(entry)) => assert_eq!(entry.index(), 2),
+            _ => panic!("the entry should be vacant"),
+        }
+
+        if let [Ok((_, b)), Ok((_, c))] = map.get_disjoint_indices_mut([0, 1]) {
+            mem::swap(b, c);
+        }
+        assert_eq!(map.first(), Some((&"b", &3)));
+        assert_eq!(map.last(), Some((&"c", &4)));
+        assert_eq!(
+            map.get_disjoint_indices_mut([1, 1, 2]),
+            [
+                Ok((&"c", &mut 4)),
+                Err(UnavailableMutError::Duplicate(0)),
+                Err(UnavailableMutError::Absent)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_retain_preserves_order() {
+        let mut map: IndexMap<u32, u32, 11> = IndexMap::new();
+        for i in [5, 3, 9, 1, 8, 2, 7] {
+            map.try_insert(i, i * 10).unwrap();
+        }
+
+        map.retain(|k, v| {
+            *v += 1;
+            k % 2 == 1
+        });
+
+        assert_eq!(
+            map.iter().try_collect::<[_; 5]>(),
+            Ok([(&5, &51), (&3, &31), (&9, &91), (&1, &11), (&7, &71)])
+        );
+
+        // the indices have been rebuilt:
+        for (index, key) in [5, 3, 9, 1, 7].into_iter().enumerate() {
+            assert_eq!(map.get(&key), Some(&(key * 10 + 1)));
+            assert_eq!(map.get_entry_at(index).map(|(k, _)| *k), Some(key));
+        }
+        assert_eq!(map.get(&8), None);
+
+        map.try_insert(4, 40).unwrap();
+        assert_eq!(map.get_entry_at(5), Some((&4, &40)));
+    }
+
+    #[test]
+    fn test_retain_mut_changes_keys() {
+        let mut map: IndexMap<u32, &str, 5> = index_map! {
+            @infer,
+            1 => "a",
+            2 => "b",
+            3 => "c",
+        }
+        .unwrap();
+
+        map.retain_mut(|k, _| {
+            *k += 100;
+            true
+        });
+
+        assert_eq!(map.get(&1), None);
+        assert_eq!(map.get(&101), Some(&"a"));
+        assert_eq!(map.get(&102), Some(&"b"));
+        assert_eq!(map.get(&103), Some(&"c"));
+    }
+
+    #[test]
+    fn test_extract_if_partial() {
+        let mut map: IndexMap<u32, &str, 11> = index_map! {
+            @infer,
+            1 => "a",
+            2 => "b",
+            3 => "c",
+            4 => "d",
+            5 => "e",
+            6 => "f",
+        }
+        .unwrap();
+
+        let mut extract = map.extract_if(|k, _| k % 2 == 0);
+        assert_eq!(extract.size_hint(), (0, Some(6)));
+        assert_eq!(extract.next(), Some((2, "b")));
+        assert_eq!(extract.size_hint(), (0, Some(4)));
+        drop(extract);
+
+        // the unvisited entries are retained in their order
+        assert_eq!(
+            map.iter().try_collect::<[_; 5]>(),
+            Ok([(&1, &"a"), (&3, &"c"), (&4, &"d"), (&5, &"e"), (&6, &"f")])
+        );
+        assert_eq!(map.get(&4), Some(&"d"));
+        assert_eq!(map.get(&6), Some(&"f"));
+
+        assert_eq!(
+            map.extract_if(|k, _| *k > 3).try_collect::<[_; 3]>(),
+            Ok([(4, "d"), (5, "e"), (6, "f")])
+        );
+        assert_eq!(
+            map.iter().try_collect::<[_; 2]>(),
+            Ok([(&1, &"a"), (&3, &"c")])
+        );
+    }
+
+    #[test]
+    fn test_extract_if_leaked() {
+        let mut map: IndexMap<u32, &str, 5> = index_map! {
+            @infer,
+            1 => "a",
+            2 => "b",
+        }
+        .unwrap();
+
+        core::mem::forget(map.extract_if(|_, _| true));
+
+        assert_eq!(map.len(), 0);
+        assert_eq!(map.get(&1), None);
+        map.try_insert(3, "c").unwrap();
+        assert_eq!(map.get_entry_at(0), Some((&3, &"c")));
+    }
+
+    #[test]
+    fn test_drain_filter_keeps_indices_valid() {
+        let mut map: IndexMap<u32, u32, 17> = (0..10).map(|i| (i, i * 10)).try_collect().unwrap();
+
+        let mut drained: [Option<(u32, u32)>; 10] =
+            map.drain_filter(|k, _| k % 2 == 0).try_collect().unwrap();
+        drained.sort_unstable();
+
+        assert_eq!(
+            drained,
+            [
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some((0, 0)),
+                Some((2, 20)),
+                Some((4, 40)),
+                Some((6, 60)),
+                Some((8, 80)),
+            ]
+        );
+        assert_eq!(
+            map.iter().try_collect::<[_; 5]>(),
+            Ok([(&1, &10), (&3, &30), (&5, &50), (&7, &70), (&9, &90)])
+        );
+        for i in 0..10 {
+            assert_eq!(map.get(&i), (i % 2 == 1).then_some(&(i * 10)));
+        }
+    }
+
+    #[test]
+    fn test_copy_storage() {
+        #[derive(Clone, Copy, Default)]
+        struct CopyState;
+
+        impl BuildHasher for CopyState {
+            type Hasher = ahash::AHasher;
+
+            fn build_hasher(&self) -> Self::Hasher {
+                ahash::AHasher::default()
+            }
+        }
+
+        type CopyMap = IndexMap<u32, u32, 11, CopyState, CopyArrayVec<(u32, u32), 11>>;
+
+        let mut map: CopyMap = (0..5).map(|i| (i, i * 10)).try_collect().unwrap();
+        let copy = map;
+
+        assert_eq!(map.shift_remove_index(1), Some((1, 10)));
+        map.try_insert(7, 70).unwrap();
+        *map.get_mut(&3).unwrap() += 1;
+
+        assert_eq!(
+            map.iter().try_collect::<[_; 5]>(),
+            Ok([(&0, &0), (&2, &20), (&3, &31), (&4, &40), (&7, &70)])
+        );
+        assert_eq!(map.get_index_of(&7), Some(4));
+        assert_eq!(
+            copy.iter().try_collect::<[_; 5]>(),
+            Ok([(&0, &0), (&1, &10), (&2, &20), (&3, &30), (&4, &40)])
+        );
+        assert_eq!(copy.get_index_of(&4), Some(4));
+
+        let ordered = *copy.as_ordered();
+        assert!(ordered.eq_ordered(&copy));
+        assert!(!ordered.eq_ordered(&map));
+    }
+
+    #[test]
+    fn test_slice_views() {
+        let mut map: IndexMap<u32, u32, 11> = (0..8).map(|i| (i, i * 10)).try_collect().unwrap();
+
+        let slice = map.get_range(2..6).unwrap();
+        assert_eq!(slice.len(), 4);
+        assert_eq!(slice.first(), Some((&2, &20)));
+        assert_eq!(slice.last(), Some((&5, &50)));
+        assert_eq!(slice.binary_search_keys(&4), Ok(2));
+        assert_eq!(slice.binary_search_by_key(&15, |_, v| *v), Err(0));
+
+        let (left, right) = slice.split_at(1);
+        assert_eq!(left.iter().try_collect(), Ok([(&2, &20)]));
+        assert_eq!(right.iter().len(), 3);
+        assert_eq!(right.get_range(3..), Some(map.get_range(6..6).unwrap()));
+        assert_eq!(map.get_range(7..9), None);
+
+        let (left, right) = map.as_mut_slice().split_at_mut(4);
+        mem::swap(left.last_mut().unwrap().1, right.first_mut().unwrap().1);
+        for (_, value) in right.get_range_mut(1..).unwrap() {
+            *value += 1;
+        }
+
+        assert_eq!(map[3], 40);
+        assert_eq!(map[4], 30);
+        assert_eq!(map.get(&7), Some(&71));
+
+        // lookups still work after the values have been modified
+        map.swap_remove_index(0);
+        assert_eq!(map.as_slice().first(), Some((&7, &71)));
+        assert_eq!(map.get(&5), Some(&51));
+    }
+
+    #[test]
+    fn test_double_ended_iterators() {
+        let mut map: IndexMap<u32, char, 11> = index_map! {
+            @infer,
+            1 => 'a',
+            2 => 'b',
+            3 => 'c',
+            4 => 'd',
+        }
+        .unwrap();
+
+        assert_eq!(
+            map.iter().rev().try_collect(),
+            Ok([(&4, &'d'), (&3, &'c'), (&2, &'b'), (&1, &'a')])
+        );
+        assert_eq!(map.keys().rev().try_collect(), Ok([&4, &3, &2, &1]));
+        assert_eq!(map.values().nth(2), Some(&'c'));
+        assert_eq!(map.iter().nth_back(1), Some((&3, &'c')));
+
+        let mut iter = map.iter_mut();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.nth(1), Some((&2, &mut 'b')));
+        if let Some((_, value)) = iter.next_back() {
+            *value = 'z';
+        }
+        assert_eq!(iter.len(), 1);
+        assert_eq!(map.values_mut().next_back(), Some(&mut 'z'));
+
+        let mut drain = map.drain();
+        assert_eq!(drain.len(), 4);
+        assert_eq!(drain.next_back(), Some((4, 'z')));
+        assert_eq!(drain.nth(1), Some((2, 'b')));
+        mem::drop(drain);
+        assert!(map.is_empty());
+
+        map.try_insert(5, 'e').unwrap();
+        map.try_insert(6, 'f').unwrap();
+        let mut into_iter = map.into_iter();
+        assert_eq!(into_iter.len(), 2);
+        assert_eq!(into_iter.next_back(), Some((6, 'f')));
+    }
+
+    #[test]
+    fn test_split_off() {
+        let mut map: IndexMap<u32, char, 7> = index_map! {
+            @infer,
+            1 => 'a',
+            2 => 'b',
+            3 => 'c',
+            4 => 'd',
+            5 => 'e',
+        }
+        .unwrap();
+
+        assert_eq!(
+            map.try_split_off::<2>(1).map(|tail| tail.len()),
+            Err(CapacityError::new(2, 4))
+        );
+        assert_eq!(map.len(), 5);
+
+        let tail: IndexMap<u32, char, 3> = map.try_split_off(2).unwrap();
+        assert_eq!(map.iter().try_collect(), Ok([(&1, &'a'), (&2, &'b')]));
+        assert_eq!(
+            tail.iter().try_collect(),
+            Ok([(&3, &'c'), (&4, &'d'), (&5, &'e')])
+        );
+
+        for key in 1..=5 {
+            assert_eq!(map.contains_key(&key), key <= 2);
+            assert_eq!(tail.contains_key(&key), key > 2);
+        }
+        assert_eq!(tail.get_index_of(&5), Some(2));
+
+        let empty: IndexMap<u32, char, 1> = map.try_split_off(2).unwrap();
+        assert!(empty.is_empty());
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    fn test_append() {
+        let mut map: IndexMap<u32, char, 4> = index_map! {
+            @infer,
+            1 => 'a',
+            2 => 'b',
+        }
+        .unwrap();
+        let mut other: IndexMap<u32, char, 3> = index_map! {
+            @infer,
+            3 => 'c',
+            1 => 'x',
+            4 => 'd',
+        }
+        .unwrap();
+
+        map.try_append(&mut other).unwrap();
+        assert!(other.is_empty());
+        assert_eq!(
+            map.iter().try_collect(),
+            Ok([(&1, &'x'), (&2, &'b'), (&3, &'c'), (&4, &'d')])
+        );
+
+        let mut other: IndexMap<u32, char, 3> = index_map! {
+            @infer,
+            2 => 'y',
+            5 => 'e',
+        }
+        .unwrap();
+
+        assert_eq!(map.try_append(&mut other), Err(CapacityError::new(4, 5)));
+        assert_eq!(other.len(), 2);
+        assert_eq!(map.get(&2), Some(&'b'));
+        assert_eq!(map.len(), 4);
+    }
+
+    #[test]
+    fn test_drain_range_collect() {
+        let mut map: IndexMap<u32, char, 7> = index_map! {
+            @infer,
+            1 => 'a',
+            2 => 'b',
+            3 => 'c',
+            4 => 'd',
+            5 => 'e',
+        }
+        .unwrap();
+
+        let drained: IndexMap<u32, char, 3> = map.drain_range(1..4).try_collect().unwrap();
+
+        assert_eq!(
+            drained.iter().try_collect(),
+            Ok([(&2, &'b'), (&3, &'c'), (&4, &'d')])
+        );
+        assert_eq!(map.iter().try_collect(), Ok([(&1, &'a'), (&5, &'e')]));
+        assert_eq!(map.get_index_of(&5), Some(1));
+        assert_eq!(map.get(&3), None);
+    }
 }
@@ -1,6 +1,7 @@
 use core::hash::{BuildHasher, Hash};
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
 
-use super::DrainFilter;
 use crate::raw::RawTable;
 
 /// A draining iterator over entries of an `ArrayMap`.
@@ -15,7 +16,8 @@ where
     R: RawTable<(K, V)>,
     B: BuildHasher,
 {
-    inner: DrainFilter<'a, K, V, fn(&K, &mut V) -> bool, R, B>,
+    iter: R::DrainIter,
+    _p: PhantomData<(&'a mut R, &'a B)>,
 }
 
 impl<'a, K, V, R, B> Drain<'a, K, V, R, B>
@@ -24,9 +26,10 @@ where
     R: RawTable<(K, V)>,
     B: BuildHasher,
 {
-    pub(crate) fn new(table: &'a mut R, build_hasher: &'a B) -> Self {
+    pub(crate) fn new(table: &'a mut R, _: &'a B) -> Self {
         Self {
-            inner: DrainFilter::new(|_, _| true, table, build_hasher),
+            iter: table.drain(),
+            _p: PhantomData,
         }
     }
 }
@@ -40,8 +43,50 @@ where
     type Item = (K, V);
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.inner.next()
+        self.iter.next()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n)
+    }
+}
+
+impl<'a, K, V, R, B> DoubleEndedIterator for Drain<'a, K, V, R, B>
+where
+    K: Eq + Hash,
+    R: RawTable<(K, V)>,
+    R::DrainIter: DoubleEndedIterator,
+    B: BuildHasher,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth_back(n)
+    }
+}
+
+impl<'a, K, V, R, B> ExactSizeIterator for Drain<'a, K, V, R, B>
+where
+    K: Eq + Hash,
+    R: RawTable<(K, V)>,
+    R::DrainIter: ExactSizeIterator,
+    B: BuildHasher,
+{
+}
+
+impl<'a, K, V, R, B> FusedIterator for Drain<'a, K, V, R, B>
+where
+    K: Eq + Hash,
+    R: RawTable<(K, V)>,
+    R::DrainIter: FusedIterator,
+    B: BuildHasher,
+{
 }
 
 #[cfg(all(test, feature = "ahash"))]
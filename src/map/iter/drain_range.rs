@@ -1,14 +1,23 @@
 use core::fmt;
 use core::hash::{BuildHasher, Hash};
 use core::iter::FusedIterator;
-use core::ops::Range;
+use core::marker::PhantomData;
 
+use crate::raw::{ArrayIndexTable, RawTable};
 use crate::IndexMap;
 
+/// A draining iterator over a range of the entries of an `IndexMap`.
+///
+/// The entries are removed from the map when the iterator is created, so
+/// dropping it early drops the remaining entries of the range.
+///
+/// This struct is created by [`IndexMap::drain_range`]. See its documentation
+/// for more.
+///
+/// [`IndexMap::drain_range`]: crate::IndexMap::drain_range
 pub struct DrainRange<'a, K: Hash + Eq, V, B: BuildHasher, const N: usize> {
-    map: &'a mut IndexMap<K, V, N, B>,
-    remaining: usize,
-    index: usize,
+    iter: <ArrayIndexTable<(K, V), N> as RawTable<(K, V)>>::DrainIter,
+    _p: PhantomData<&'a mut IndexMap<K, V, N, B>>,
 }
 
 impl<'a, K, V, B, const N: usize> DrainRange<'a, K, V, B, N>
@@ -17,17 +26,12 @@ where
     B: BuildHasher,
 {
     #[must_use]
-    pub(crate) fn new(map: &'a mut IndexMap<K, V, N, B>, range: Range<usize>) -> Self {
+    pub(crate) fn new(iter: <ArrayIndexTable<(K, V), N> as RawTable<(K, V)>>::DrainIter) -> Self {
         Self {
-            map,
-            remaining: range.end - range.start,
-            index: range.start,
+            iter,
+            _p: PhantomData,
         }
     }
-
-    pub(crate) fn iter(&self) -> impl Iterator<Item = (&'_ K, &'_ V)> {
-        (self.index..(self.index + self.remaining)).filter_map(|index| self.map.get_entry_at(index))
-    }
 }
 
 impl<'a, K, V, B, const N: usize> Iterator for DrainRange<'a, K, V, B, N>
@@ -38,17 +42,15 @@ where
     type Item = (K, V);
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.remaining == 0 {
-            return None;
-        }
-
-        self.remaining -= 1;
-
-        self.map.shift_remove_index(self.index)
+        self.iter.next()
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.remaining, Some(self.remaining))
+        self.iter.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n)
     }
 }
 
@@ -58,19 +60,11 @@ where
     B: BuildHasher,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.remaining == 0 {
-            return None;
-        }
-
-        self.remaining -= 1;
-
-        self.map.shift_remove_index(self.index + self.remaining)
+        self.iter.next_back()
     }
-}
 
-impl<'a, K: Hash + Eq, V, B: BuildHasher, const N: usize> Drop for DrainRange<'a, K, V, B, N> {
-    fn drop(&mut self) {
-        self.for_each(drop);
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth_back(n)
     }
 }
 
@@ -81,7 +75,9 @@ where
     B: BuildHasher,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_list().entries(self.iter()).finish()
+        f.debug_list()
+            .entries(self.iter.as_slice().iter().map(|(key, value)| (key, value)))
+            .finish()
     }
 }
 
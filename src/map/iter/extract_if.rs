@@ -0,0 +1,114 @@
+use core::fmt;
+use core::hash::{BuildHasher, Hash};
+use core::iter::FusedIterator;
+
+use crate::array_vec::{ArrayVec, VecStorage};
+use crate::raw::{ArrayIndexTable, Compaction};
+use crate::utils;
+
+/// An iterator which removes and yields the entries of an `IndexMap` for which
+/// the predicate `F` returns `true`.
+///
+/// The entries are visited in their order and the remaining entries keep
+/// their relative order.
+///
+/// This struct is created by [`IndexMap::extract_if`]. See its documentation
+/// for more.
+///
+/// [`IndexMap::extract_if`]: crate::IndexMap::extract_if
+pub struct ExtractIf<'a, K, V, F, B, const N: usize, E = ArrayVec<(K, V), N>>
+where
+    B: BuildHasher,
+    F: FnMut(&K, &mut V) -> bool,
+    K: Hash + Eq,
+    E: VecStorage<(K, V), N>,
+{
+    f: F,
+    compaction: Compaction,
+    table: &'a mut ArrayIndexTable<(K, V), N, E>,
+    build_hasher: &'a B,
+}
+
+impl<'a, K, V, F, B, const N: usize, E> ExtractIf<'a, K, V, F, B, N, E>
+where
+    B: BuildHasher,
+    F: FnMut(&K, &mut V) -> bool,
+    K: Hash + Eq,
+    E: VecStorage<(K, V), N>,
+{
+    pub(crate) fn new(
+        f: F,
+        table: &'a mut ArrayIndexTable<(K, V), N, E>,
+        build_hasher: &'a B,
+    ) -> Self {
+        Self {
+            f,
+            compaction: table.begin_compaction(),
+            table,
+            build_hasher,
+        }
+    }
+}
+
+impl<'a, K, V, F, B, const N: usize, E> Iterator for ExtractIf<'a, K, V, F, B, N, E>
+where
+    B: BuildHasher,
+    F: FnMut(&K, &mut V) -> bool,
+    K: Hash + Eq,
+    E: VecStorage<(K, V), N>,
+{
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let f = &mut self.f;
+
+        // SAFETY: the compaction has been started in `new` and is only finished in
+        // `drop`
+        unsafe {
+            self.table
+                .compaction_next(&mut self.compaction, |(k, v)| !f(k, v))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.compaction.remaining()))
+    }
+}
+
+impl<'a, K, V, F, B, const N: usize, E> FusedIterator for ExtractIf<'a, K, V, F, B, N, E>
+where
+    B: BuildHasher,
+    F: FnMut(&K, &mut V) -> bool,
+    K: Hash + Eq,
+    E: VecStorage<(K, V), N>,
+{
+}
+
+impl<'a, K, V, F, B, const N: usize, E> Drop for ExtractIf<'a, K, V, F, B, N, E>
+where
+    B: BuildHasher,
+    F: FnMut(&K, &mut V) -> bool,
+    K: Hash + Eq,
+    E: VecStorage<(K, V), N>,
+{
+    fn drop(&mut self) {
+        // SAFETY: the compaction has been started in `new` and has not been finished
+        // yet
+        unsafe {
+            self.table
+                .finish_compaction(&self.compaction, utils::key_hasher(self.build_hasher));
+        }
+    }
+}
+
+impl<'a, K, V, F, B, const N: usize, E> fmt::Debug for ExtractIf<'a, K, V, F, B, N, E>
+where
+    B: BuildHasher,
+    F: FnMut(&K, &mut V) -> bool,
+    K: Hash + Eq,
+    E: VecStorage<(K, V), N>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExtractIf").finish_non_exhaustive()
+    }
+}
@@ -22,6 +22,34 @@ impl<'a, K: 'a, V: 'a, R: RawTableIter<(K, V)>> Iterator for Iter<'a, K, V, R> {
             None => None,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.0.nth(n).map(|(key, value)| (key, value))
+    }
+}
+
+impl<'a, K: 'a, V: 'a, R: RawTableIter<(K, V)>> DoubleEndedIterator for Iter<'a, K, V, R>
+where
+    R::Iter<'a>: DoubleEndedIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(key, value)| (key, value))
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.0.nth_back(n).map(|(key, value)| (key, value))
+    }
+}
+
+impl<'a, K: 'a, V: 'a, R: RawTableIter<(K, V)>> ExactSizeIterator for Iter<'a, K, V, R>
+//
+where
+    R::Iter<'a>: ExactSizeIterator
+{
 }
 
 impl<'a, K, V, R: RawTableIter<(K, V)>> Clone for Iter<'a, K, V, R>
@@ -26,6 +26,34 @@ impl<'a, K: 'a, V: 'a, R: RawTableIter<(K, V)>> Iterator for IterMut<'a, K, V, R
             None => None,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n).map(|(key, value)| (&*key, value))
+    }
+}
+
+impl<'a, K: 'a, V: 'a, R: RawTableIter<(K, V)>> DoubleEndedIterator for IterMut<'a, K, V, R>
+where
+    R::IterMut<'a>: DoubleEndedIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(key, value)| (&*key, value))
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth_back(n).map(|(key, value)| (&*key, value))
+    }
+}
+
+impl<'a, K: 'a, V: 'a, R: RawTableIter<(K, V)>> ExactSizeIterator for IterMut<'a, K, V, R>
+//
+where
+    R::IterMut<'a>: ExactSizeIterator
+{
 }
 
 impl<'a, K: 'a, V: 'a, R: RawTableIter<(K, V)>> fmt::Debug for IterMut<'a, K, V, R>
@@ -1,19 +1,23 @@
 mod drain;
 mod drain_filter;
 mod drain_range;
+mod extract_if;
 mod iter;
 mod iter_mut;
 mod keys;
 mod map_iter;
+mod slice_iter;
 mod values;
 mod values_mut;
 
 pub use drain::*;
 pub use drain_filter::*;
 pub use drain_range::*;
+pub use extract_if::*;
 pub use iter::*;
 pub use iter_mut::*;
 pub use keys::*;
 pub use map_iter::*;
+pub use slice_iter::*;
 pub use values::*;
 pub use values_mut::*;
@@ -0,0 +1,96 @@
+use core::iter::FusedIterator;
+use core::{fmt, slice};
+
+/// An iterator over the entries of a [`Slice`].
+///
+/// This struct is created by [`Slice::iter`]. See its documentation for more.
+///
+/// [`Slice`]: crate::map::Slice
+/// [`Slice::iter`]: crate::map::Slice::iter
+#[must_use]
+pub struct SliceIter<'a, K, V>(slice::Iter<'a, (K, V)>);
+
+impl<'a, K, V> SliceIter<'a, K, V> {
+    pub(crate) fn new(entries: &'a [(K, V)]) -> Self {
+        Self(entries.iter())
+    }
+}
+
+impl<'a, K, V> Iterator for SliceIter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(key, value)| (key, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for SliceIter<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(key, value)| (key, value))
+    }
+}
+
+impl<K, V> ExactSizeIterator for SliceIter<'_, K, V> {}
+
+impl<K, V> FusedIterator for SliceIter<'_, K, V> {}
+
+impl<K, V> Clone for SliceIter<'_, K, V> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for SliceIter<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+/// An iterator over the entries of a [`Slice`], with mutable references to
+/// the values.
+///
+/// This struct is created by [`Slice::iter_mut`]. See its documentation for
+/// more.
+///
+/// [`Slice`]: crate::map::Slice
+/// [`Slice::iter_mut`]: crate::map::Slice::iter_mut
+#[must_use]
+pub struct SliceIterMut<'a, K, V>(slice::IterMut<'a, (K, V)>);
+
+impl<'a, K, V> SliceIterMut<'a, K, V> {
+    pub(crate) fn new(entries: &'a mut [(K, V)]) -> Self {
+        Self(entries.iter_mut())
+    }
+}
+
+impl<'a, K, V> Iterator for SliceIterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(key, value)| (&*key, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for SliceIterMut<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(key, value)| (&*key, value))
+    }
+}
+
+impl<K, V> ExactSizeIterator for SliceIterMut<'_, K, V> {}
+
+impl<K, V> FusedIterator for SliceIterMut<'_, K, V> {}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for SliceIterMut<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.0.as_slice()).finish()
+    }
+}
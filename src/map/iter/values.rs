@@ -26,6 +26,34 @@ impl<'a, K, V, R: RawTableIter<(K, V)>> Iterator for Values<'a, K, V, R> {
     fn next(&mut self) -> Option<Self::Item> {
         self.0.next().map(|(_, v)| v)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.0.nth(n).map(|(_, v)| v)
+    }
+}
+
+impl<'a, K: 'a, V: 'a, R: RawTableIter<(K, V)>> DoubleEndedIterator for Values<'a, K, V, R>
+where
+    R::Iter<'a>: DoubleEndedIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(_, v)| v)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.0.nth_back(n).map(|(_, v)| v)
+    }
+}
+
+impl<'a, K: 'a, V: 'a, R: RawTableIter<(K, V)>> ExactSizeIterator for Values<'a, K, V, R>
+//
+where
+    R::Iter<'a>: ExactSizeIterator
+{
 }
 
 impl<'a, K: fmt::Debug, V: fmt::Debug, R: RawTableIter<(K, V)>> fmt::Debug for Values<'a, K, V, R>
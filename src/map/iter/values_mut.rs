@@ -27,6 +27,34 @@ impl<'a, K: 'a, V: 'a, R: RawTableIter<(K, V)>> Iterator for ValuesMut<'a, K, V,
     fn next(&mut self) -> Option<Self::Item> {
         self.0.next().map(|(_, v)| v)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.0.nth(n).map(|(_, v)| v)
+    }
+}
+
+impl<'a, K: 'a, V: 'a, R: RawTableIter<(K, V)>> DoubleEndedIterator for ValuesMut<'a, K, V, R>
+where
+    R::IterMut<'a>: DoubleEndedIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(_, v)| v)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.0.nth_back(n).map(|(_, v)| v)
+    }
+}
+
+impl<'a, K: 'a, V: 'a, R: RawTableIter<(K, V)>> ExactSizeIterator for ValuesMut<'a, K, V, R>
+//
+where
+    R::IterMut<'a>: ExactSizeIterator
+{
 }
 
 impl<'a, K: 'a, V: 'a, R: RawTableIter<(K, V)>> fmt::Debug for ValuesMut<'a, K, V, R>
@@ -1,3 +1,47 @@
+/// Creates an [`ArrayMap`] from a list of key-value pairs.
+///
+/// Without further arguments, the capacity of the map is the number of
+/// entries. `@capacity N` creates a map with the capacity `N`, which can be
+/// larger than the number of entries, and `@infer` returns a
+/// `Result<ArrayMap<K, V, N>, CapacityError>`, where `N` is inferred from the
+/// context. A custom hasher can be passed with `@build_hasher => hasher`.
+///
+/// If all keys are literals, duplicate keys are rejected at compile time.
+///
+/// [`ArrayMap`]: crate::ArrayMap
+///
+/// # Examples
+///
+/// ```
+/// use array_map::{array_map, ArrayMap};
+///
+/// let map = array_map!(1 => "a", 2 => "b");
+/// assert_eq!(map.capacity(), 2);
+///
+/// let mut map = array_map!(@capacity 4, 1 => "a", 2 => "b");
+/// assert_eq!(map.capacity(), 4);
+/// map.try_insert(3, "c")?;
+///
+/// let map: ArrayMap<_, _, 3> = array_map!(@infer, 1 => "a")?;
+/// assert_eq!(map.len(), 1);
+/// # Ok::<_, array_map::CapacityError>(())
+/// ```
+///
+/// Duplicate literal keys do not compile:
+///
+/// ```compile_fail
+/// use array_map::array_map;
+///
+/// let map = array_map!("a" => 1, "b" => 2, "a" => 3);
+/// ```
+///
+/// Neither do more entries than the specified capacity:
+///
+/// ```compile_fail
+/// use array_map::array_map;
+///
+/// let map = array_map!(@capacity 1, "a" => 1, "b" => 2);
+/// ```
 #[macro_export]
 macro_rules! array_map {
     // replaces `_t` with the provided expression `e`
@@ -6,10 +50,48 @@ macro_rules! array_map {
     (@count $($x:expr),*) => {
         <[()]>::len(&[$( $crate::array_map!(@replace $x ()) ),*])
     };
+    // rejects duplicate literal keys, because the pattern of a duplicate key is unreachable
+    (@check_duplicates $first:literal $(, $key:literal )*) => {
+        #[deny(unreachable_patterns)]
+        #[allow(clippy::manual_range_patterns)]
+        let _ = ::core::matches!($first, $first $( | $key )*);
+    };
+    // literal keys are checked for duplicates, before they are passed to the other rules
+    ( @infer, $( @build_hasher => $bh:expr, )? $( $key:literal => $value:expr ),+ $(,)? ) => {
+        {
+            $crate::array_map!(@check_duplicates $( $key ),+);
+            $crate::array_map!( @infer, $( @build_hasher => $bh, )? $( @unchecked $key => $value ),+)
+        }
+    };
+    ( @capacity $capacity:expr $(, @build_hasher => $bh:expr)? $(, $key:literal => $value:expr )+ $(,)? ) => {
+        {
+            $crate::array_map!(@check_duplicates $( $key ),+);
+            $crate::array_map!( @capacity $capacity $(, @build_hasher => $bh)? $(, @unchecked $key => $value )+)
+        }
+    };
+    ( $( @build_hasher => $bh:expr, )? $( $key:literal => $value:expr ),+ $(,)? ) => {
+        {
+            $crate::array_map!(@check_duplicates $( $key ),+);
+            $crate::array_map!( $( @build_hasher => $bh, )? $( @unchecked $key => $value ),+)
+        }
+    };
+    // removes the marker of already checked keys
+    ( @infer, $( @build_hasher => $bh:expr, )? $( @unchecked $key:literal => $value:expr ),+ ) => {
+        $crate::array_map!( @infer, $( @build_hasher => $bh, )? $( ($key) => $value ),+)
+    };
+    ( @capacity $capacity:expr $(, @build_hasher => $bh:expr)? $(, @unchecked $key:literal => $value:expr )+ ) => {
+        $crate::array_map!( @capacity $capacity $(, @build_hasher => $bh)? $(, ($key) => $value )+)
+    };
+    ( $( @build_hasher => $bh:expr, )? $( @unchecked $key:literal => $value:expr ),+ ) => {
+        $crate::array_map!( $( @build_hasher => $bh, )? $( ($key) => $value ),+)
+    };
     // To support trailing commas in the macro
     ( @infer, $( @build_hasher => $bh:expr, )? $( $key:expr => $value:expr, )+ ) => {
         $crate::array_map!( @infer, $( @build_hasher => $bh, )? $( $key => $value ),+)
     };
+    ( @capacity $capacity:expr $(, @build_hasher => $bh:expr)? $(, $key:expr => $value:expr )+ , ) => {
+        $crate::array_map!( @capacity $capacity $(, @build_hasher => $bh)? $(, $key => $value )+)
+    };
     ( $( @build_hasher => $bh:expr, )? $( $key:expr => $value:expr, )+ ) => {
         $crate::array_map!( $( @build_hasher => $bh, )? $( $key => $value ),+)
     };
@@ -17,16 +99,7 @@ macro_rules! array_map {
         $crate::map::ArrayMap::with_build_hasher($bh)
     };
     ( @helper_construct ) => {
-        {
-            #[cfg(feature = "ahash")]
-            {
-                $crate::map::ArrayMap::new()
-            }
-            #[cfg(not(feature = "ahash"))]
-            {
-                ::core::compile_error!("`ahash` feature is disabled, so a build_hasher must be specified explicitly!")
-            }
-        }
+        $crate::map::ArrayMap::with_build_hasher($crate::default_build_hasher!())
     };
     ( @infer, $( @build_hasher => $bh:expr, )? $( $key:expr => $value:expr ),* ) => {
         {
@@ -43,19 +116,60 @@ macro_rules! array_map {
             value
         }
     };
+    ( @capacity $capacity:expr $(, @build_hasher => $bh:expr)? $(, $key:expr => $value:expr )* ) => {
+        {
+            const {
+                ::core::assert!(
+                    $crate::array_map!(@count $($key),*) <= $capacity,
+                    "`array_map` macro has more entries than the specified capacity"
+                );
+            }
+
+            let _map: $crate::map::ArrayMap<_, _, { $capacity }, _> = $crate::array_map!(
+                @infer,
+                $( @build_hasher => $bh, )?
+                $( $key => $value ),*
+            ).expect("`array_map` macro does not count correctly!");
+
+            _map
+        }
+    };
     ( $( @build_hasher => $bh:expr, )? $( $key:expr => $value:expr ),* ) => {
         {
             let _map: $crate::map::ArrayMap<_, _, { $crate::array_map!(@count $($key),*) }, _> = $crate::array_map!(
                 @infer,
                 $( @build_hasher => $bh, )?
                 $( $key => $value ),*
-            ).expect("`arraymap` macro does not count correctly!");
+            ).expect("`array_map` macro does not count correctly!");
 
             _map
         }
     };
 }
 
+/// Creates an [`IndexMap`] from a list of key-value pairs, which keeps the
+/// order of the entries.
+///
+/// The macro supports the same arguments as [`array_map!`].
+///
+/// [`IndexMap`]: crate::IndexMap
+///
+/// # Examples
+///
+/// ```
+/// use array_map::index_map;
+///
+/// let map = index_map!(@capacity 3, "b" => 1, "a" => 2);
+///
+/// assert_eq!(map.capacity(), 3);
+/// assert_eq!(map.keys().collect::<Vec<_>>(), vec![&"b", &"a"]);
+/// ```
+///
+/// ```compile_fail
+/// use array_map::index_map;
+///
+/// let map = index_map!('a' => 1, 'a' => 2);
+/// ```
 #[macro_export]
 macro_rules! index_map {
     // replaces `_t` with the provided expression `e`
@@ -64,10 +178,48 @@ macro_rules! index_map {
     (@count $($x:expr),*) => {
         <[()]>::len(&[$( $crate::index_map!(@replace $x ()) ),*])
     };
+    // rejects duplicate literal keys, because the pattern of a duplicate key is unreachable
+    (@check_duplicates $first:literal $(, $key:literal )*) => {
+        #[deny(unreachable_patterns)]
+        #[allow(clippy::manual_range_patterns)]
+        let _ = ::core::matches!($first, $first $( | $key )*);
+    };
+    // literal keys are checked for duplicates, before they are passed to the other rules
+    ( @infer, $( @build_hasher => $bh:expr, )? $( $key:literal => $value:expr ),+ $(,)? ) => {
+        {
+            $crate::index_map!(@check_duplicates $( $key ),+);
+            $crate::index_map!( @infer, $( @build_hasher => $bh, )? $( @unchecked $key => $value ),+)
+        }
+    };
+    ( @capacity $capacity:expr $(, @build_hasher => $bh:expr)? $(, $key:literal => $value:expr )+ $(,)? ) => {
+        {
+            $crate::index_map!(@check_duplicates $( $key ),+);
+            $crate::index_map!( @capacity $capacity $(, @build_hasher => $bh)? $(, @unchecked $key => $value )+)
+        }
+    };
+    ( $( @build_hasher => $bh:expr, )? $( $key:literal => $value:expr ),+ $(,)? ) => {
+        {
+            $crate::index_map!(@check_duplicates $( $key ),+);
+            $crate::index_map!( $( @build_hasher => $bh, )? $( @unchecked $key => $value ),+)
+        }
+    };
+    // removes the marker of already checked keys
+    ( @infer, $( @build_hasher => $bh:expr, )? $( @unchecked $key:literal => $value:expr ),+ ) => {
+        $crate::index_map!( @infer, $( @build_hasher => $bh, )? $( ($key) => $value ),+)
+    };
+    ( @capacity $capacity:expr $(, @build_hasher => $bh:expr)? $(, @unchecked $key:literal => $value:expr )+ ) => {
+        $crate::index_map!( @capacity $capacity $(, @build_hasher => $bh)? $(, ($key) => $value )+)
+    };
+    ( $( @build_hasher => $bh:expr, )? $( @unchecked $key:literal => $value:expr ),+ ) => {
+        $crate::index_map!( $( @build_hasher => $bh, )? $( ($key) => $value ),+)
+    };
     // To support trailing commas in the macro
     ( @infer, $( @build_hasher => $bh:expr, )? $( $key:expr => $value:expr, )+ ) => {
         $crate::index_map!( @infer, $( @build_hasher => $bh, )? $( $key => $value ),+)
     };
+    ( @capacity $capacity:expr $(, @build_hasher => $bh:expr)? $(, $key:expr => $value:expr )+ , ) => {
+        $crate::index_map!( @capacity $capacity $(, @build_hasher => $bh)? $(, $key => $value )+)
+    };
     ( $( @build_hasher => $bh:expr, )? $( $key:expr => $value:expr, )+ ) => {
         $crate::index_map!( $( @build_hasher => $bh, )? $( $key => $value ),+)
     };
@@ -75,16 +227,7 @@ macro_rules! index_map {
         $crate::map::IndexMap::with_build_hasher($bh)
     };
     ( @helper_construct ) => {
-        {
-            #[cfg(feature = "ahash")]
-            {
-                $crate::map::IndexMap::new()
-            }
-            #[cfg(not(feature = "ahash"))]
-            {
-                ::core::compile_error!("`ahash` feature is disabled, so a build_hasher must be specified explicitly!")
-            }
-        }
+        $crate::map::IndexMap::with_build_hasher($crate::default_build_hasher!())
     };
     ( @infer, $( @build_hasher => $bh:expr, )? $( $key:expr => $value:expr ),* ) => {
         {
@@ -101,6 +244,24 @@ macro_rules! index_map {
             value
         }
     };
+    ( @capacity $capacity:expr $(, @build_hasher => $bh:expr)? $(, $key:expr => $value:expr )* ) => {
+        {
+            const {
+                ::core::assert!(
+                    $crate::index_map!(@count $($key),*) <= $capacity,
+                    "`index_map` macro has more entries than the specified capacity"
+                );
+            }
+
+            let _map: $crate::IndexMap<_, _, { $capacity }, _> = $crate::index_map!(
+                @infer,
+                $( @build_hasher => $bh, )?
+                $( $key => $value ),*
+            ).expect("`index_map` macro does not count correctly!");
+
+            _map
+        }
+    };
     ( $( @build_hasher => $bh:expr, )? $( $key:expr => $value:expr ),* ) => {
         {
             let _map: $crate::IndexMap<_, _, { $crate::index_map!(@count $($key),*) }, _> = $crate::index_map!(
@@ -118,6 +279,7 @@ macro_rules! index_map {
 mod tests {
     use pretty_assertions::assert_eq;
 
+    use crate::ext::IteratorExt;
     use crate::map::array_map_facade::DefaultHashBuilder;
     use crate::map::ArrayMap;
 
@@ -167,6 +329,38 @@ mod tests {
         };
     }
 
+    #[test]
+    fn test_arraymap_macro_capacity() {
+        let map: ArrayMap<_, _, 4> = array_map!(@capacity 4, "a" => 1, "b" => 2,);
+        assert_eq!(map.len(), 2);
+
+        let map: ArrayMap<(), (), 2> = array_map!(@capacity 2);
+        assert!(map.is_empty());
+
+        let map = array_map!(@capacity 3, @build_hasher => DefaultHashBuilder::default(), 1 => 1);
+        assert_eq!(map.capacity(), 3);
+    }
+
+    #[test]
+    fn test_arraymap_macro_expression_keys() {
+        let key = "key";
+        let map = array_map!(key => 1, "other" => 2);
+
+        assert_eq!(map.get("key"), Some(&1));
+        assert_eq!(map.get("other"), Some(&2));
+    }
+
+    #[test]
+    fn test_index_map_macro_capacity() {
+        let map = index_map!(@capacity 5, 'c' => 1, 'a' => 2, 'b' => 3);
+
+        assert_eq!(map.capacity(), 5);
+        assert_eq!(
+            map.keys().copied().try_collect::<[_; 3]>(),
+            Ok(['c', 'a', 'b'])
+        );
+    }
+
     #[test]
     fn test_arraymap_macro_infer_flag() {
         let _map: ArrayMap<_, _, 5> = array_map! {
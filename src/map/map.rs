@@ -0,0 +1,266 @@
+/// A trait that is implemented by structs that are maps.
+///
+/// This allows to write code that is generic over where the entries are
+/// stored, so it works with an `ArrayMap` on embedded targets and for example
+/// with a `HashMap` on the host.
+///
+/// Unlike the inherent methods of the maps, the lookup methods take the key
+/// type itself instead of a borrowed form of it, because hash based and
+/// ordered maps require different bounds on the borrowed form.
+pub trait Map<K, V> {
+    type Error;
+
+    /// Returns the number of entries in the map.
+    #[must_use]
+    fn len(&self) -> usize;
+
+    /// Returns a reference to the value corresponding to the key.
+    #[must_use]
+    fn get(&self, key: &K) -> Option<&V>;
+
+    /// Returns a mutable reference to the value corresponding to the key.
+    #[must_use]
+    fn get_mut(&mut self, key: &K) -> Option<&mut V>;
+
+    /// Inserts a key-value pair into the map.
+    ///
+    /// If the map did not have this key present, `None` is returned.
+    ///
+    /// If the map did have this key present, the value is updated, and the old
+    /// value is returned.
+    ///
+    /// # Errors
+    ///
+    /// If the map is full or it failed to insert the entry.
+    fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, Self::Error>;
+
+    /// Removes a key from the map, returning the value at the key if the key
+    /// was previously in the map.
+    fn remove(&mut self, key: &K) -> Option<V>;
+
+    /// Returns `true` if the map contains a value for the key.
+    #[must_use]
+    fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Returns `true` if the map is empty, `false` if it is not empty.
+    #[must_use]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+pub trait MapIter<K, V>: Map<K, V> + IntoIterator<Item = (K, V)> {
+    /// An immutable iterator over the entries of the map.
+    type Iter<'a>: Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a,
+        Self: 'a;
+
+    /// An iterator over the entries of the map, with mutable references to
+    /// the values.
+    type IterMut<'a>: Iterator<Item = (&'a K, &'a mut V)>
+    where
+        K: 'a,
+        V: 'a,
+        Self: 'a;
+
+    /// An iterator visiting all entries in the iteration order of the map.
+    /// The iterator element type is `(&'a K, &'a V)`.
+    fn iter(&self) -> Self::Iter<'_>;
+
+    /// An iterator visiting all entries in the iteration order of the map,
+    /// with mutable references to the values. The iterator element type is
+    /// `(&'a K, &'a mut V)`.
+    fn iter_mut(&mut self) -> Self::IterMut<'_>;
+
+    /// Returns `true` if both maps contain the same entries.
+    ///
+    /// The maps can be of different types, for example an `ArrayMap` can be
+    /// compared with a `HashMap` or an `ArrayMap` with a different capacity.
+    #[must_use]
+    fn map_eq<M: Map<K, V>>(&self, other: &M) -> bool
+    where
+        V: PartialEq,
+    {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(key, value)| other.get(key).is_some_and(|other| value == other))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> Map<K, V> for ::std::collections::HashMap<K, V, S>
+where
+    K: ::core::hash::Hash + Eq,
+    S: ::core::hash::BuildHasher,
+{
+    type Error = !;
+
+    fn len(&self) -> usize {
+        Self::len(self)
+    }
+
+    fn get(&self, key: &K) -> Option<&V> {
+        Self::get(self, key)
+    }
+
+    fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        Self::get_mut(self, key)
+    }
+
+    fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, Self::Error> {
+        Ok(Self::insert(self, key, value))
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        Self::remove(self, key)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> MapIter<K, V> for ::std::collections::HashMap<K, V, S>
+where
+    K: ::core::hash::Hash + Eq,
+    S: ::core::hash::BuildHasher,
+{
+    type Iter<'a> = ::std::collections::hash_map::Iter<'a, K, V>
+    where
+        K: 'a,
+        V: 'a,
+        Self: 'a;
+    type IterMut<'a> = ::std::collections::hash_map::IterMut<'a, K, V>
+    where
+        K: 'a,
+        V: 'a,
+        Self: 'a;
+
+    fn iter(&self) -> Self::Iter<'_> {
+        Self::iter(self)
+    }
+
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        Self::iter_mut(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Ord, V> Map<K, V> for ::std::collections::BTreeMap<K, V> {
+    type Error = !;
+
+    fn len(&self) -> usize {
+        Self::len(self)
+    }
+
+    fn get(&self, key: &K) -> Option<&V> {
+        Self::get(self, key)
+    }
+
+    fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        Self::get_mut(self, key)
+    }
+
+    fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, Self::Error> {
+        Ok(Self::insert(self, key, value))
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        Self::remove(self, key)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Ord, V> MapIter<K, V> for ::std::collections::BTreeMap<K, V> {
+    type Iter<'a> = ::std::collections::btree_map::Iter<'a, K, V>
+    where
+        K: 'a,
+        V: 'a,
+        Self: 'a;
+    type IterMut<'a> = ::std::collections::btree_map::IterMut<'a, K, V>
+    where
+        K: 'a,
+        V: 'a,
+        Self: 'a;
+
+    fn iter(&self) -> Self::Iter<'_> {
+        Self::iter(self)
+    }
+
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        Self::iter_mut(self)
+    }
+}
+
+#[cfg(all(test, feature = "ahash"))]
+mod tests {
+    use super::*;
+
+    use crate::ext::IteratorExt;
+    use crate::{ArrayMap, IndexMap};
+    use pretty_assertions::assert_eq;
+
+    fn count_words<M: Map<&'static str, usize>>(map: &mut M) -> Result<(), M::Error> {
+        for word in ["a", "b", "a", "c", "a"] {
+            if let Some(count) = map.get_mut(&word) {
+                *count += 1;
+            } else {
+                map.try_insert(word, 1)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_generic_over_array_maps() {
+        let mut array_map: ArrayMap<&str, usize, 5> = ArrayMap::new();
+        count_words(&mut array_map).unwrap();
+
+        let mut index_map: IndexMap<&str, usize, 3> = IndexMap::new();
+        count_words(&mut index_map).unwrap();
+
+        assert_eq!(Map::get(&array_map, &"a"), Some(&3));
+        assert_eq!(Map::len(&index_map), 3);
+        assert!(array_map.map_eq(&index_map));
+
+        let mut full: ArrayMap<&str, usize, 2> = ArrayMap::new();
+        assert!(count_words(&mut full).is_err());
+
+        assert_eq!(Map::remove(&mut index_map, &"b"), Some(1));
+        assert!(!Map::contains_key(&index_map, &"b"));
+        assert!(!array_map.map_eq(&index_map));
+
+        for (_, value) in MapIter::iter_mut(&mut index_map) {
+            *value *= 2;
+        }
+        assert_eq!(
+            MapIter::iter(&index_map).try_collect(),
+            Ok([(&"a", &6), (&"c", &2)])
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_generic_over_std_maps() {
+        use std::collections::{BTreeMap, HashMap};
+
+        let mut hash_map = HashMap::new();
+        let Ok(()) = count_words(&mut hash_map);
+
+        let mut btree_map = BTreeMap::new();
+        let Ok(()) = count_words(&mut btree_map);
+
+        let mut array_map: ArrayMap<&str, usize, 5> = ArrayMap::new();
+        count_words(&mut array_map).unwrap();
+
+        assert!(array_map.map_eq(&hash_map));
+        assert!(btree_map.map_eq(&array_map));
+        assert_eq!(
+            MapIter::iter(&btree_map).collect::<Vec<_>>(),
+            vec![(&"a", &3), (&"b", &1), (&"c", &1)]
+        );
+    }
+}
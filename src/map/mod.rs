@@ -3,11 +3,19 @@ mod entry;
 mod index_map;
 pub mod iter;
 mod macros;
+mod map;
 mod occupied;
+mod ordered_index_map;
+mod reseed;
+mod slice;
 mod vacant;
 
 pub use array_map_facade::*;
 pub use entry::*;
 pub use index_map::*;
+pub use map::*;
 pub use occupied::*;
+pub use ordered_index_map::*;
+pub use reseed::*;
+pub use slice::*;
 pub use vacant::*;
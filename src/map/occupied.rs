@@ -1,8 +1,9 @@
 use core::hash::{BuildHasher, Hash};
 use core::{fmt, mem};
 
+use crate::array_vec::VecStorage;
 use crate::map::VacantEntry;
-use crate::raw::RawTable;
+use crate::raw::{ArrayIndexTable, RawTable};
 use crate::utils;
 
 /// A view into an occupied entry in an `ArrayMap`. It is part of the [`Entry`]
@@ -229,6 +230,37 @@ impl<'a, K: Hash + Eq, V, R: RawTable<(K, V)>, B: BuildHasher> OccupiedEntry<'a,
     }
 }
 
+impl<'a, K, V, B: BuildHasher, const N: usize, E>
+    OccupiedEntry<'a, K, V, ArrayIndexTable<(K, V), N, E>, B>
+where
+    E: VecStorage<(K, V), N>,
+{
+    /// Returns the index of the entry in the map.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use array_map::map::Entry;
+    /// use array_map::{index_map, IndexMap};
+    ///
+    /// let mut map: IndexMap<&str, u32, 5> = index_map! {
+    ///     @infer,
+    ///     "a" => 1,
+    ///     "b" => 2,
+    /// }?;
+    ///
+    /// if let Entry::Occupied(entry) = map.entry("b")? {
+    ///     assert_eq!(entry.index(), 1);
+    /// }
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    #[must_use]
+    pub fn index(&self) -> usize {
+        // SAFETY: self has exclusive access to the table, so self.ident is valid
+        unsafe { self.table.index_from_ident(self.ident) }.index()
+    }
+}
+
 impl<'a, K, V, R: RawTable<(K, V)>, B> fmt::Debug for OccupiedEntry<'a, K, V, R, B>
 where
     K: fmt::Debug,
@@ -0,0 +1,235 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{BuildHasher, Hash, Hasher};
+use core::ops::{Deref, DerefMut};
+use core::ptr;
+
+use crate::array_vec::{ArrayVec, VecStorage};
+use crate::map::{DefaultHashBuilder, IndexMap};
+
+/// A wrapper around an [`IndexMap`], which takes the order of the entries into
+/// account when comparing or hashing maps.
+///
+/// The [`PartialOrd`] and [`Ord`] implementations compare the `(K, V)` pairs
+/// lexicographically in index order.
+///
+/// Like the [`IndexMap`] it wraps, this type is only [`Copy`] if its entries
+/// are stored in a [`CopyArrayVec`] and the entries and the hasher are
+/// [`Copy`].
+///
+/// [`CopyArrayVec`]: crate::array_vec::CopyArrayVec
+///
+/// # Examples
+///
+/// ```
+/// use array_map::map::OrderedIndexMap;
+/// use array_map::IndexMap;
+///
+/// let mut a: IndexMap<&str, u32, 5> = IndexMap::new();
+/// a.try_insert("a", 1)?;
+/// a.try_insert("b", 2)?;
+///
+/// let mut b: IndexMap<&str, u32, 5> = IndexMap::new();
+/// b.try_insert("b", 2)?;
+/// b.try_insert("a", 1)?;
+///
+/// assert_eq!(a, b);
+/// assert_ne!(a.as_ordered(), b.as_ordered());
+/// assert!(OrderedIndexMap(a) < OrderedIndexMap(b));
+/// # Ok::<_, array_map::CapacityError>(())
+/// ```
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct OrderedIndexMap<K, V, const N: usize, B = DefaultHashBuilder, E = ArrayVec<(K, V), N>>(
+    pub IndexMap<K, V, N, B, E>,
+)
+where
+    E: VecStorage<(K, V), N>;
+
+impl<K, V, const N: usize, B: BuildHasher, E> IndexMap<K, V, N, B, E>
+where
+    K: Hash + Eq,
+    E: VecStorage<(K, V), N>,
+{
+    /// Returns `true` if both maps contain the same entries in the same
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::IndexMap;
+    ///
+    /// let mut a: IndexMap<&str, u32, 5> = IndexMap::new();
+    /// a.try_insert("a", 1)?;
+    /// a.try_insert("b", 2)?;
+    ///
+    /// let mut b: IndexMap<&str, u32, 7> = IndexMap::new();
+    /// b.try_insert("b", 2)?;
+    /// b.try_insert("a", 1)?;
+    ///
+    /// assert_eq!(a.eq_ordered(&b), false);
+    ///
+    /// b.swap_indices(0, 1);
+    /// assert_eq!(a.eq_ordered(&b), true);
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    #[must_use]
+    pub fn eq_ordered<W, const M: usize, C, F>(&self, other: &IndexMap<K, W, M, C, F>) -> bool
+    where
+        V: PartialEq<W>,
+        C: BuildHasher,
+        F: VecStorage<(K, W), M>,
+    {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|((k1, v1), (k2, v2))| k1 == k2 && v1 == v2)
+    }
+
+    /// Returns a reference to the map as an [`OrderedIndexMap`].
+    #[must_use]
+    pub const fn as_ordered(&self) -> &OrderedIndexMap<K, V, N, B, E> {
+        // SAFETY: `OrderedIndexMap` is a `repr(transparent)` wrapper around the map
+        unsafe { &*ptr::from_ref(self).cast::<OrderedIndexMap<K, V, N, B, E>>() }
+    }
+}
+
+impl<K, V, const N: usize, B, E> Deref for OrderedIndexMap<K, V, N, B, E>
+where
+    E: VecStorage<(K, V), N>,
+{
+    type Target = IndexMap<K, V, N, B, E>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<K, V, const N: usize, B, E> DerefMut for OrderedIndexMap<K, V, N, B, E>
+where
+    E: VecStorage<(K, V), N>,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<K, V, const N: usize, B, E> From<IndexMap<K, V, N, B, E>> for OrderedIndexMap<K, V, N, B, E>
+where
+    E: VecStorage<(K, V), N>,
+{
+    fn from(value: IndexMap<K, V, N, B, E>) -> Self {
+        Self(value)
+    }
+}
+
+impl<K, V, const N: usize, B, E> From<OrderedIndexMap<K, V, N, B, E>> for IndexMap<K, V, N, B, E>
+where
+    E: VecStorage<(K, V), N>,
+{
+    fn from(value: OrderedIndexMap<K, V, N, B, E>) -> Self {
+        value.0
+    }
+}
+
+impl<K, V, const N: usize, B, E> fmt::Debug for OrderedIndexMap<K, V, N, B, E>
+where
+    K: fmt::Debug,
+    V: fmt::Debug,
+    B: BuildHasher,
+    E: VecStorage<(K, V), N>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<K, V, const N: usize, B, E> PartialEq for OrderedIndexMap<K, V, N, B, E>
+where
+    K: Hash + Eq,
+    V: PartialEq,
+    B: BuildHasher,
+    E: VecStorage<(K, V), N>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_ordered(&other.0)
+    }
+}
+
+impl<K, V, const N: usize, B, E> Eq for OrderedIndexMap<K, V, N, B, E>
+where
+    K: Hash + Eq,
+    V: Eq,
+    B: BuildHasher,
+    E: VecStorage<(K, V), N>,
+{
+}
+
+impl<K, V, const N: usize, B, E> PartialOrd for OrderedIndexMap<K, V, N, B, E>
+where
+    K: Hash + Eq + PartialOrd,
+    V: PartialOrd,
+    B: BuildHasher,
+    E: VecStorage<(K, V), N>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0.iter().partial_cmp(other.0.iter())
+    }
+}
+
+impl<K, V, const N: usize, B, E> Ord for OrderedIndexMap<K, V, N, B, E>
+where
+    K: Hash + Ord,
+    V: Ord,
+    B: BuildHasher,
+    E: VecStorage<(K, V), N>,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.iter().cmp(other.0.iter())
+    }
+}
+
+impl<K, V, const N: usize, B, E> Hash for OrderedIndexMap<K, V, N, B, E>
+where
+    K: Hash + Eq,
+    V: Hash,
+    B: BuildHasher,
+    E: VecStorage<(K, V), N>,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash_ordered(state);
+    }
+}
+
+#[cfg(all(test, feature = "ahash"))]
+mod tests {
+    use core::cmp::Ordering;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::index_map;
+
+    #[test]
+    fn test_ordered_cmp() {
+        let a: IndexMap<u32, u32, 3> = index_map! { 1 => 1, 2 => 2, 3 => 3 };
+        let b: IndexMap<u32, u32, 3> = index_map! { 1 => 1, 3 => 3, 2 => 2 };
+        let c: IndexMap<u32, u32, 3> = index_map! { 1 => 1, 2 => 2, 3 => 4 };
+
+        assert_eq!(a.as_ordered().cmp(b.as_ordered()), Ordering::Less);
+        assert_eq!(a.as_ordered().cmp(c.as_ordered()), Ordering::Less);
+        assert_eq!(b.as_ordered().cmp(a.as_ordered()), Ordering::Greater);
+        assert_eq!(a.as_ordered().cmp(a.as_ordered()), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_ordered_prefix_is_less() {
+        let a: IndexMap<u32, u32, 3> = index_map! { 1 => 1, 2 => 2, 3 => 3 };
+        let mut b = a.clone();
+        b.pop();
+
+        assert!(b.as_ordered() < a.as_ordered());
+        assert!(!b.eq_ordered(&a));
+    }
+}
@@ -0,0 +1,53 @@
+use core::hash::BuildHasher;
+
+/// A [`BuildHasher`] that can replace its seed.
+///
+/// Maps with such a [`BuildHasher`] can recover from hash-flooding, by
+/// switching to a fresh seed and rebuilding their table. See
+/// [`ArrayMapFacade::try_insert_guarded`] for more.
+///
+/// [`ArrayMapFacade::try_insert_guarded`]: crate::ArrayMapFacade::try_insert_guarded
+pub trait ReseedBuildHasher: BuildHasher {
+    /// Replaces the seed of the [`BuildHasher`] with a fresh one.
+    ///
+    /// The hashes produced after this call should be unrelated to the ones
+    /// produced before it.
+    fn reseed(&mut self);
+
+    /// Returns the longest probe run that is still considered normal for a
+    /// table with `len` entries and the given capacity.
+    ///
+    /// Linear probing with a good hash function has an expected longest probe
+    /// run of `O(log n)`, so the default is a generous multiple of that. It is
+    /// clamped to half of the capacity, otherwise the limit could never be
+    /// exceeded in small tables.
+    ///
+    /// The expected probe length also grows with `(1 / (1 - load))^2 / 2`, so
+    /// this is added on top of the limit. A (nearly) full table therefore
+    /// allows probe runs over the whole table.
+    #[must_use]
+    fn max_probe_length(&self, len: usize, capacity: usize) -> usize {
+        let base = (2 * capacity.checked_ilog2().unwrap_or(0) as usize + 8).min(capacity / 2);
+        let free = capacity.saturating_sub(len).max(1);
+
+        base.saturating_add((capacity / free).saturating_pow(2) / 2)
+    }
+}
+
+#[cfg(feature = "ahash")]
+impl ReseedBuildHasher for ahash::RandomState {
+    fn reseed(&mut self) {
+        // the new seeds are derived from the old (secret) state, so they can not be
+        // predicted by someone who only knows the keys
+        let mix = |value: u64| crate::utils::make_hash::<u64, u64, Self>(self, &value);
+
+        *self = Self::generate_with(mix(0), mix(1), mix(2), mix(3));
+    }
+}
+
+#[cfg(feature = "std")]
+impl ReseedBuildHasher for ::std::collections::hash_map::RandomState {
+    fn reseed(&mut self) {
+        *self = Self::new();
+    }
+}
@@ -0,0 +1,359 @@
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::ops::{Index, RangeBounds};
+use core::{fmt, ptr};
+
+use crate::map::iter::{SliceIter, SliceIterMut};
+
+/// A borrowed view over a range of the entries of an
+/// [`IndexMap`](crate::IndexMap).
+///
+/// The entries are in the same order as in the map. Only the values can be
+/// modified through a mutable slice, so the keys stay in sync with the map.
+///
+/// This type is created by [`IndexMap::as_slice`],
+/// [`IndexMap::as_mut_slice`] and [`IndexMap::get_range`].
+///
+/// [`IndexMap::as_slice`]: crate::IndexMap::as_slice
+/// [`IndexMap::as_mut_slice`]: crate::IndexMap::as_mut_slice
+/// [`IndexMap::get_range`]: crate::IndexMap::get_range
+#[repr(transparent)]
+pub struct Slice<K, V> {
+    entries: [(K, V)],
+}
+
+impl<K, V> Slice<K, V> {
+    pub(crate) const fn from_slice(entries: &[(K, V)]) -> &Self {
+        // SAFETY: `Slice` is a transparent wrapper around `[(K, V)]`
+        unsafe { &*(ptr::from_ref(entries) as *const Self) }
+    }
+
+    pub(crate) fn from_mut_slice(entries: &mut [(K, V)]) -> &mut Self {
+        // SAFETY: `Slice` is a transparent wrapper around `[(K, V)]`
+        unsafe { &mut *(ptr::from_mut(entries) as *mut Self) }
+    }
+
+    /// Returns the number of entries in the slice.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the slice contains no entries.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the entry at the index or `None` if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::{index_map, IndexMap};
+    ///
+    /// let map: IndexMap<&str, u32, 5> = index_map! {
+    ///     @infer,
+    ///     "a" => 1,
+    ///     "b" => 2,
+    ///     "c" => 3,
+    /// }?;
+    ///
+    /// let slice = map.get_range(1..).unwrap();
+    /// assert_eq!(slice.get_entry_at(0), Some((&"b", &2)));
+    /// assert_eq!(slice.get_entry_at(2), None);
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    #[must_use]
+    pub fn get_entry_at(&self, index: usize) -> Option<(&K, &V)> {
+        let (key, value) = self.entries.get(index)?;
+
+        Some((key, value))
+    }
+
+    /// Returns the entry at the index with a mutable reference to its value or
+    /// `None` if the index is out of bounds.
+    #[must_use]
+    pub fn get_entry_at_mut(&mut self, index: usize) -> Option<(&K, &mut V)> {
+        let (key, value) = self.entries.get_mut(index)?;
+
+        Some((key, value))
+    }
+
+    /// Returns a subslice with the entries in the range or `None` if the range
+    /// is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::{index_map, IndexMap};
+    ///
+    /// let map: IndexMap<&str, u32, 5> = index_map! {
+    ///     @infer,
+    ///     "a" => 1,
+    ///     "b" => 2,
+    ///     "c" => 3,
+    ///     "d" => 4,
+    /// }?;
+    ///
+    /// let slice = map.as_slice().get_range(1..3).unwrap();
+    /// assert_eq!(slice.get_range(1..), map.get_range(2..3));
+    /// assert_eq!(slice.get_range(..3), None);
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    #[must_use]
+    pub fn get_range<R: RangeBounds<usize>>(&self, range: R) -> Option<&Self> {
+        let range = (range.start_bound().cloned(), range.end_bound().cloned());
+
+        self.entries.get(range).map(Self::from_slice)
+    }
+
+    /// Returns a mutable subslice with the entries in the range or `None` if
+    /// the range is out of bounds.
+    #[must_use]
+    pub fn get_range_mut<R: RangeBounds<usize>>(&mut self, range: R) -> Option<&mut Self> {
+        let range = (range.start_bound().cloned(), range.end_bound().cloned());
+
+        self.entries.get_mut(range).map(Self::from_mut_slice)
+    }
+
+    /// Returns the first entry of the slice or `None` if it is empty.
+    #[must_use]
+    pub fn first(&self) -> Option<(&K, &V)> {
+        let (key, value) = self.entries.first()?;
+
+        Some((key, value))
+    }
+
+    /// Returns the first entry of the slice with a mutable reference to its
+    /// value or `None` if it is empty.
+    #[must_use]
+    pub fn first_mut(&mut self) -> Option<(&K, &mut V)> {
+        let (key, value) = self.entries.first_mut()?;
+
+        Some((key, value))
+    }
+
+    /// Returns the last entry of the slice or `None` if it is empty.
+    #[must_use]
+    pub fn last(&self) -> Option<(&K, &V)> {
+        let (key, value) = self.entries.last()?;
+
+        Some((key, value))
+    }
+
+    /// Returns the last entry of the slice with a mutable reference to its
+    /// value or `None` if it is empty.
+    #[must_use]
+    pub fn last_mut(&mut self) -> Option<(&K, &mut V)> {
+        let (key, value) = self.entries.last_mut()?;
+
+        Some((key, value))
+    }
+
+    /// Divides the slice into two at the index.
+    ///
+    /// The first slice contains the entries in `[0, index)` and the second one
+    /// those in `[index, len)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::{index_map, IndexMap};
+    ///
+    /// let map: IndexMap<&str, u32, 5> = index_map! {
+    ///     @infer,
+    ///     "a" => 1,
+    ///     "b" => 2,
+    ///     "c" => 3,
+    /// }?;
+    ///
+    /// let (left, right) = map.as_slice().split_at(1);
+    /// assert_eq!(left.len(), 1);
+    /// assert_eq!(left.last(), Some((&"a", &1)));
+    /// assert_eq!(right.first(), Some((&"b", &2)));
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    #[must_use]
+    #[track_caller]
+    pub const fn split_at(&self, index: usize) -> (&Self, &Self) {
+        let (left, right) = self.entries.split_at(index);
+
+        (Self::from_slice(left), Self::from_slice(right))
+    }
+
+    /// Divides the mutable slice into two at the index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    #[must_use]
+    #[track_caller]
+    pub fn split_at_mut(&mut self, index: usize) -> (&mut Self, &mut Self) {
+        let (left, right) = self.entries.split_at_mut(index);
+
+        (Self::from_mut_slice(left), Self::from_mut_slice(right))
+    }
+
+    /// Binary searches the slice for the key, assuming that the slice is
+    /// sorted by its keys.
+    ///
+    /// See [`slice::binary_search`] for more.
+    ///
+    /// # Errors
+    ///
+    /// If no matching entry is found, the index where one could be inserted
+    /// while maintaining the order is returned as the error.
+    pub fn binary_search_keys(&self, key: &K) -> Result<usize, usize>
+    where
+        K: Ord,
+    {
+        self.binary_search_by(|other, _| other.cmp(key))
+    }
+
+    /// Binary searches the slice with the comparator function, which should
+    /// be consistent with the order of the slice.
+    ///
+    /// See [`slice::binary_search_by`] for more.
+    ///
+    /// # Errors
+    ///
+    /// If no matching entry is found, the index where one could be inserted
+    /// while maintaining the order is returned as the error.
+    pub fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&K, &V) -> Ordering,
+    {
+        self.entries.binary_search_by(|(key, value)| f(key, value))
+    }
+
+    /// Binary searches the slice with the key extraction function, assuming
+    /// that the slice is sorted by the extracted keys.
+    ///
+    /// See [`slice::binary_search_by_key`] for more.
+    ///
+    /// # Errors
+    ///
+    /// If no matching entry is found, the index where one could be inserted
+    /// while maintaining the order is returned as the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::{index_map, IndexMap};
+    ///
+    /// let map: IndexMap<&str, u32, 7> = index_map! {
+    ///     @infer,
+    ///     "a" => 1,
+    ///     "b" => 3,
+    ///     "c" => 5,
+    /// }?;
+    ///
+    /// let slice = map.as_slice();
+    /// assert_eq!(slice.binary_search_by_key(&3, |_, value| *value), Ok(1));
+    /// assert_eq!(slice.binary_search_by_key(&4, |_, value| *value), Err(2));
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    pub fn binary_search_by_key<Q, F>(&self, key: &Q, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&K, &V) -> Q,
+        Q: Ord,
+    {
+        self.binary_search_by(|k, v| f(k, v).cmp(key))
+    }
+
+    /// Returns an iterator over the entries of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::ext::IteratorExt;
+    /// use array_map::{index_map, IndexMap};
+    ///
+    /// let map: IndexMap<&str, u32, 5> = index_map! {
+    ///     @infer,
+    ///     "a" => 1,
+    ///     "b" => 2,
+    ///     "c" => 3,
+    /// }?;
+    ///
+    /// assert_eq!(
+    ///     map.get_range(1..).unwrap().iter().rev().try_collect(),
+    ///     Ok([(&"c", &3), (&"b", &2)])
+    /// );
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    pub fn iter(&self) -> SliceIter<'_, K, V> {
+        SliceIter::new(&self.entries)
+    }
+
+    /// Returns an iterator over the entries of the slice, with mutable
+    /// references to the values.
+    pub fn iter_mut(&mut self) -> SliceIterMut<'_, K, V> {
+        SliceIterMut::new(&mut self.entries)
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a Slice<K, V> {
+    type IntoIter = SliceIter<'a, K, V>;
+    type Item = (&'a K, &'a V);
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a mut Slice<K, V> {
+    type IntoIter = SliceIterMut<'a, K, V>;
+    type Item = (&'a K, &'a mut V);
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<K, V> Index<usize> for Slice<K, V> {
+    type Output = V;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get_entry_at(index)
+            .map(|entry| entry.1)
+            .expect("index is out of bounds")
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for Slice<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: PartialEq, V: PartialEq> PartialEq for Slice<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.entries == other.entries
+    }
+}
+
+impl<K: Eq, V: Eq> Eq for Slice<K, V> {}
+
+impl<K: PartialOrd, V: PartialOrd> PartialOrd for Slice<K, V> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.entries.partial_cmp(&other.entries)
+    }
+}
+
+impl<K: Ord, V: Ord> Ord for Slice<K, V> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.entries.cmp(&other.entries)
+    }
+}
+
+impl<K: Hash, V: Hash> Hash for Slice<K, V> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.entries.hash(state);
+    }
+}
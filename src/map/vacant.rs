@@ -2,8 +2,9 @@ use core::fmt;
 use core::hash::{BuildHasher, Hash};
 use core::marker::PhantomData;
 
+use crate::array_vec::VecStorage;
 use crate::map::OccupiedEntry;
-use crate::raw::RawTable;
+use crate::raw::{ArrayIndexTable, RawTable};
 use crate::{invariant, unreachable_unchecked, utils};
 
 /// A view into a vacant entry in an `ArrayMap`. It is part of the [`Entry`]
@@ -136,6 +137,92 @@ impl<'a, K: Hash, V, R: RawTable<(K, V)>, B: BuildHasher> VacantEntry<'a, K, V,
     }
 }
 
+impl<'a, K: Hash, V, B: BuildHasher, const N: usize, E>
+    VacantEntry<'a, K, V, ArrayIndexTable<(K, V), N, E>, B>
+where
+    E: VecStorage<(K, V), N>,
+{
+    /// Returns the index the entry will have when it is inserted, which is the
+    /// length of the map.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use array_map::map::Entry;
+    /// use array_map::{index_map, IndexMap};
+    ///
+    /// let mut map: IndexMap<&str, u32, 5> = index_map! {
+    ///     @infer,
+    ///     "a" => 1,
+    ///     "b" => 2,
+    /// }?;
+    ///
+    /// if let Entry::Vacant(entry) = map.entry("c")? {
+    ///     assert_eq!(entry.index(), 2);
+    /// }
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    #[must_use]
+    pub fn index(&self) -> usize {
+        self.table.len()
+    }
+
+    /// Inserts the entry's key and the given value at the `index` of the map,
+    /// shifting all entries after it to the right, and returns a mutable
+    /// reference to the value.
+    ///
+    /// # Panics
+    ///
+    /// If the `index` is greater than the length of the map.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use array_map::map::Entry;
+    /// use array_map::{index_map, IndexMap};
+    ///
+    /// let mut map: IndexMap<&str, u32, 5> = index_map! {
+    ///     @infer,
+    ///     "a" => 1,
+    ///     "b" => 2,
+    /// }?;
+    ///
+    /// if let Entry::Vacant(entry) = map.entry("c")? {
+    ///     assert_eq!(entry.shift_insert(1, 3), &mut 3);
+    /// }
+    ///
+    /// assert_eq!(map.get_entry_at(1), Some((&"c", &3)));
+    /// assert_eq!(map.get_entry_at(2), Some((&"b", &2)));
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    #[track_caller]
+    pub fn shift_insert(self, index: usize, value: V) -> &'a mut V {
+        let len = self.table.len();
+        assert!(
+            index <= len,
+            "index out of bounds: the len is {len} but the index is {index}"
+        );
+
+        let hash = utils::make_hash::<K, K, B>(self.build_hasher, &self.key);
+        let table = self.table;
+        let result = table.try_insert(
+            hash,
+            (self.key, value),
+            utils::key_hasher(self.build_hasher),
+        );
+
+        let Ok(ident) = result else {
+            unreachable_unchecked!("there must be free space for a vacant entry!")
+        };
+
+        table.move_index(len, index);
+
+        // SAFETY: moving the entry does not invalidate its ident
+        let (_, value) = unsafe { table.get_unchecked_mut(ident) };
+        value
+    }
+}
+
 impl<'a, K, V, R, B> fmt::Debug for VacantEntry<'a, K, V, R, B>
 where
     K: fmt::Debug,
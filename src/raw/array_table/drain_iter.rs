@@ -3,12 +3,19 @@ use core::{array, fmt, iter, slice};
 
 use crate::ext::ToIter;
 
+/// An owning iterator over the entries of an [`ArrayTable`](super::ArrayTable).
 #[must_use = "iterators are lazy and do nothing unless consumed"]
-pub struct DrainIter<T, const N: usize>(array::IntoIter<Option<T>, N>);
+pub struct DrainIter<T, const N: usize> {
+    iter: array::IntoIter<Option<T>, N>,
+    remaining: usize,
+}
 
 impl<T, const N: usize> DrainIter<T, N> {
-    pub(crate) fn new(data: [Option<T>; N]) -> Self {
-        Self(data.into_iter())
+    pub(crate) fn new(data: [Option<T>; N], len: usize) -> Self {
+        Self {
+            iter: data.into_iter(),
+            remaining: len,
+        }
     }
 }
 
@@ -16,10 +23,28 @@ impl<T, const N: usize> Iterator for DrainIter<T, N> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.0.by_ref().flatten().next()
+        let entry = self.iter.by_ref().flatten().next()?;
+        self.remaining = self.remaining.saturating_sub(1);
+
+        Some(entry)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
     }
 }
 
+impl<T, const N: usize> DoubleEndedIterator for DrainIter<T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let entry = self.iter.by_ref().rev().flatten().next()?;
+        self.remaining = self.remaining.saturating_sub(1);
+
+        Some(entry)
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for DrainIter<T, N> {}
+
 impl<T, const N: usize> FusedIterator for DrainIter<T, N> {}
 
 impl<T, const N: usize> ToIter for DrainIter<T, N> {
@@ -29,7 +54,7 @@ impl<T, const N: usize> ToIter for DrainIter<T, N> {
         Self::Item: 'a;
 
     fn iter(&self) -> Self::Iter<'_> {
-        self.0.iter().flatten()
+        self.iter.iter().flatten()
     }
 }
 
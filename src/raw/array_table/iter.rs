@@ -0,0 +1,63 @@
+use core::iter::FusedIterator;
+use core::{fmt, slice};
+
+/// An iterator over the entries of an [`ArrayTable`](super::ArrayTable).
+///
+/// The table knows its length, so the iterator is an
+/// [`ExactSizeIterator`].
+pub struct Iter<'a, T> {
+    iter: slice::Iter<'a, Option<T>>,
+    remaining: usize,
+}
+
+impl<'a, T> Iter<'a, T> {
+    pub(crate) fn new(slots: &'a [Option<T>], len: usize) -> Self {
+        Self {
+            iter: slots.iter(),
+            remaining: len,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry = self.iter.by_ref().flatten().next()?;
+        self.remaining = self.remaining.saturating_sub(1);
+
+        Some(entry)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let entry = self.iter.by_ref().rev().flatten().next()?;
+        self.remaining = self.remaining.saturating_sub(1);
+
+        Some(entry)
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T> FusedIterator for Iter<'a, T> {}
+
+impl<'a, T> Clone for Iter<'a, T> {
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            remaining: self.remaining,
+        }
+    }
+}
+
+impl<'a, T: fmt::Debug> fmt::Debug for Iter<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
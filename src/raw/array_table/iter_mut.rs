@@ -1,14 +1,19 @@
+use core::iter::FusedIterator;
 use core::{iter, slice};
 
 use crate::ext::ToIter;
 
 pub struct IterMut<'a, T> {
     iter: slice::IterMut<'a, Option<T>>,
+    remaining: usize,
 }
 
 impl<'a, T> IterMut<'a, T> {
-    pub(crate) fn new(iter: slice::IterMut<'a, Option<T>>) -> Self {
-        Self { iter }
+    pub(crate) fn new(iter: slice::IterMut<'a, Option<T>>, len: usize) -> Self {
+        Self {
+            iter,
+            remaining: len,
+        }
     }
 }
 
@@ -16,10 +21,30 @@ impl<'a, T> Iterator for IterMut<'a, T> {
     type Item = &'a mut T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter.by_ref().flatten().next()
+        let entry = self.iter.by_ref().flatten().next()?;
+        self.remaining = self.remaining.saturating_sub(1);
+
+        Some(entry)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
     }
 }
 
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let entry = self.iter.by_ref().rev().flatten().next()?;
+        self.remaining = self.remaining.saturating_sub(1);
+
+        Some(entry)
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IterMut<'a, T> {}
+
+impl<'a, T> FusedIterator for IterMut<'a, T> {}
+
 impl<'a, T> ToIter for IterMut<'a, T> {
     type Item = T;
     type Iter<'b> = iter::Flatten<<slice::IterMut<'b, Option<T>> as ToIter>::Iter<'b>>
@@ -1,7 +1,9 @@
 mod drain_iter;
+mod iter;
 mod iter_mut;
 mod table;
 
 pub use drain_iter::DrainIter;
+pub use iter::Iter;
 pub use iter_mut::IterMut;
 pub use table::*;
@@ -1,8 +1,10 @@
-use core::{array, fmt, iter, mem, slice};
+use core::{array, fmt, iter, mem};
 
-use super::{DrainIter, IterMut};
+use super::{DrainIter, Iter, IterMut};
 
 use crate::errors::{CapacityError, UnavailableMutError};
+#[cfg(feature = "rayon")]
+use crate::raw::SlotTable;
 use crate::raw::{FixedSizeTable, RawTable, RawTableIter, TableIndex};
 use crate::utils::{self, ArrayExt, IterCircular, UnwrapExpectExt};
 use crate::{invariant, unreachable_unchecked};
@@ -167,7 +169,9 @@ impl<T, const N: usize> RawTable<T> for ArrayTable<T, N> {
         value: T,
         _: impl Fn(&T) -> u64,
     ) -> Result<Self::Ident, Self::InsertError> {
-        let index = self.find_insert_slot(hash).ok_or(CapacityError)?;
+        let index = self
+            .find_insert_slot(hash)
+            .ok_or_else(|| CapacityError::new(N, self.len + 1))?;
 
         unsafe {
             *self.data.get_unchecked_mut(index) = Some(value);
@@ -207,9 +211,9 @@ impl<T, const N: usize> RawTable<T> for ArrayTable<T, N> {
 
     fn drain(&mut self) -> Self::DrainIter {
         let data = mem::replace(&mut self.data, utils::none_array());
-        self.len = 0;
+        let len = mem::replace(&mut self.len, 0);
 
-        DrainIter::new(data)
+        DrainIter::new(data, len)
     }
 
     fn capacity(&self) -> usize {
@@ -220,6 +224,18 @@ impl<T, const N: usize> RawTable<T> for ArrayTable<T, N> {
         self.len
     }
 
+    fn probe_length(&self, ident: &Self::Ident, hash: u64) -> usize {
+        let start = utils::adjust_hash::<N>(hash);
+        let index = ident.index();
+
+        if start <= index {
+            index - start
+        } else {
+            // wrap around
+            N - start + index
+        }
+    }
+
     fn get_each_mut<const M: usize>(
         &mut self,
         hashes: [u64; M],
@@ -256,21 +272,56 @@ impl<T, const N: usize> RawTable<T> for ArrayTable<T, N> {
 
 impl<T, const N: usize> FixedSizeTable<T, N> for ArrayTable<T, N> {}
 
+#[cfg(feature = "rayon")]
+impl<T, const N: usize> SlotTable<T> for ArrayTable<T, N> {
+    type Slot = Option<T>;
+    type Slots = [Option<T>; N];
+
+    fn slots(&self) -> &[Option<T>] {
+        &self.data
+    }
+
+    unsafe fn slots_mut(&mut self) -> &mut [Option<T>] {
+        &mut self.data
+    }
+
+    fn take_slots(&mut self) -> Self::Slots {
+        self.len = 0;
+
+        mem::replace(&mut self.data, utils::none_array())
+    }
+
+    fn retain_slots(&mut self, keep: &[bool], hasher: impl Fn(&T) -> u64) {
+        let mut removed = false;
+
+        for (index, slot) in self.data.iter_mut().enumerate() {
+            if !keep[index] && slot.take().is_some() {
+                removed = true;
+            }
+        }
+
+        // the entries behind a vacated slot might no longer be found
+        if removed {
+            self.rehash(hasher);
+        }
+    }
+}
+
 impl<'a, T, const N: usize> IntoIterator for &'a ArrayTable<T, N> {
-    type IntoIter = iter::Flatten<slice::Iter<'a, Option<T>>>;
+    type IntoIter = Iter<'a, T>;
     type Item = &'a T;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.data.iter().flatten()
+        Iter::new(&self.data, self.len)
     }
 }
 
 impl<'a, T, const N: usize> IntoIterator for &'a mut ArrayTable<T, N> {
-    type IntoIter = iter::Flatten<slice::IterMut<'a, Option<T>>>;
+    type IntoIter = IterMut<'a, T>;
     type Item = &'a mut T;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.data.iter_mut().flatten()
+        IterMut::new(self.data.iter_mut(), self.len)
     }
 }
 
@@ -283,7 +334,7 @@ impl<T, const N: usize> RawTableIter<T> for ArrayTable<T, N> {
         T: 'a;
 
     fn iter_mut(&mut self) -> Self::IterMut<'_> {
-        IterMut::new(self.data.iter_mut())
+        self.into_iter()
     }
 
     fn iter(&self) -> Self::Iter<'_> {
@@ -292,11 +343,11 @@ impl<T, const N: usize> RawTableIter<T> for ArrayTable<T, N> {
 }
 
 impl<T, const N: usize> IntoIterator for ArrayTable<T, N> {
-    type IntoIter = iter::Flatten<array::IntoIter<Option<T>, N>>;
+    type IntoIter = DrainIter<T, N>;
     type Item = T;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.data.into_iter().flatten()
+        DrainIter::new(self.data, self.len)
     }
 }
 
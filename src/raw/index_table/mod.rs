@@ -1,8 +1,3 @@
-mod array_vec;
-mod flat_iter;
 mod table;
 
-pub use table::{ArrayIndexTable, IndexTable};
-
-use array_vec::ArrayVec;
-use flat_iter::FlatIter;
+pub use table::{ArrayIndexTable, Compaction, IndexTable};
@@ -1,80 +1,211 @@
-use core::{array, iter, mem};
+use core::cmp::Ordering;
+use core::marker::PhantomData;
+use core::ops::Range;
+use core::{array, mem, ptr, slice};
 
+use crate::array_vec::{ArrayVec, IntoIter, VecStorage};
 use crate::errors::{CapacityError, UnavailableMutError};
-use crate::invariant;
-use crate::raw::{ArrayTable, FixedSizeTable, IterMut, RawTable, RawTableIter, TableIndex};
-use crate::utils::{ArrayExt, UnwrapExpectExt};
-
-use super::{ArrayVec, FlatIter};
+use crate::ext::IteratorExt;
+#[cfg(feature = "rayon")]
+use crate::raw::SlotTable;
+use crate::raw::{ArrayTable, FixedSizeTable, RawTable, RawTableIter, TableIndex};
+use crate::utils::{self, ArrayExt, UnwrapExpectExt};
+use crate::{invariant, unreachable_unchecked};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct IndexTable<T, R, const N: usize>
+pub struct IndexTable<T, R, const N: usize, E = ArrayVec<T, N>>
 where
-    // TODO: maybe this should be: (u64, TableIndex<N>)?
-    R: FixedSizeTable<TableIndex<N>, N>,
+    R: FixedSizeTable<(u64, TableIndex<N>), N>,
 {
     /// Mapping from the entry hash to its index.
+    ///
+    /// The hash is stored next to the index, so the indices can be moved
+    /// around without hashing the entries again.
     indices: R,
     /// Entries in their order.
-    entries: ArrayVec<T, N>,
+    entries: E,
+    _p: PhantomData<T>,
 }
 
-pub type ArrayIndexTable<T, const N: usize> = IndexTable<T, ArrayTable<TableIndex<N>, N>, N>;
+pub type ArrayIndexTable<T, const N: usize, E = ArrayVec<T, N>> =
+    IndexTable<T, ArrayTable<(u64, TableIndex<N>), N>, N, E>;
 
-impl<T, R: FixedSizeTable<TableIndex<N>, N>, const N: usize> IndexTable<T, R, N> {
+impl<T, R, const N: usize, E> IndexTable<T, R, N, E>
+where
+    R: FixedSizeTable<(u64, TableIndex<N>), N>,
+    E: VecStorage<T, N>,
+{
     /// Removes an entry from the table, preserving the insertion order by
     /// shifting all the following elements to the left.
-    pub unsafe fn shift_remove(
-        &mut self,
-        ident: <Self as RawTable<T>>::Ident,
-        hasher: impl Fn(&T) -> u64,
-    ) -> T {
-        let entry_index = self.indices.remove(ident, |value| unsafe {
-            hasher(self.entries.get_unchecked(*value))
-        });
+    ///
+    /// # Safety
+    ///
+    /// The ident must be valid.
+    ///
+    /// # Complexity
+    ///
+    /// This completes in `O(n)` time without hashing any of the entries.
+    pub unsafe fn shift_remove(&mut self, ident: <Self as RawTable<T>>::Ident) -> T {
+        let (_, entry_index) = self.indices.remove(ident, |(hash, _)| *hash);
 
-        let result = self.entries.remove_unchecked(entry_index);
+        let result = self
+            .entries
+            .as_mut_array_vec()
+            .remove_unchecked(entry_index.index());
 
         // all indices that have been moved must be updated:
+        self.close_gap(entry_index);
+
+        result
+    }
 
-        if self.entries.is_empty() {
-            return result;
+    /// Decrements all indices that are greater than the removed index, so they
+    /// point to the entries again after those have been shifted to the left.
+    ///
+    /// Every index is visited once.
+    unsafe fn close_gap(&mut self, removed: TableIndex<N>) {
+        // nothing has been shifted if the last entry has been removed
+        if removed.index() == self.entries.as_array_vec().len() {
+            return;
         }
 
-        for new_index in entry_index.index()..self.entries.len() {
-            let old_index = TableIndex::new(new_index + 1);
-            let new_index = TableIndex::new(new_index);
-            let hash = hasher(self.entries.get_unchecked(new_index));
+        for ident in self.indices.iter_idents() {
+            let (_, index) = self.indices.get_unchecked_mut(ident);
 
-            let value = self
-                .indices
-                .get_mut(hash, |other| other == &old_index)
-                .expect_unchecked("the entry should still be present");
-            *value = new_index;
+            if *index > removed {
+                *index = TableIndex::new(index.index() - 1);
+            }
         }
+    }
 
-        result
+    /// Shortens the table, keeping the first `len` entries and dropping the
+    /// rest.
+    ///
+    /// # Complexity
+    ///
+    /// This completes in `O(n)` time without hashing any of the entries.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.entries.as_array_vec().len() {
+            return;
+        }
+
+        self.indices
+            .retain(|(_, index)| index.index() < len, |(hash, _)| *hash);
+
+        while self.entries.as_array_vec().len() > len {
+            mem::drop(self.entries.as_mut_array_vec().pop());
+        }
+    }
+
+    /// Removes the entries in the range from the table and returns them,
+    /// shifting all following entries to the left.
+    ///
+    /// # Safety
+    ///
+    /// The range must be valid (`range.start <= range.end <= self.len()`).
+    ///
+    /// # Complexity
+    ///
+    /// This completes in `O(n)` time without hashing any of the entries.
+    pub unsafe fn drain_range(&mut self, range: Range<usize>) -> IntoIter<T, N> {
+        self.remove_indices(range.clone(), |_, _| {});
+
+        self.entries
+            .as_mut_array_vec()
+            .remove_range::<N>(range)
+            .into_iter()
+    }
+
+    /// Moves the entries in `[at, len)` into a new table, keeping the first
+    /// `at` entries in this one.
+    ///
+    /// The stored hashes are reused, so the entries must be hashed the same
+    /// way in the new table.
+    ///
+    /// # Errors
+    ///
+    /// If the moved entries do not fit into the new table, a [`CapacityError`]
+    /// is returned and this table is left unchanged.
+    ///
+    /// # Safety
+    ///
+    /// The split index must be valid (`at <= self.len()`).
+    ///
+    /// # Complexity
+    ///
+    /// This completes in `O(n)` time without hashing any of the entries.
+    pub unsafe fn try_split_off<S, F, const M: usize>(
+        &mut self,
+        at: usize,
+    ) -> Result<IndexTable<T, S, M, F>, CapacityError>
+    where
+        S: FixedSizeTable<(u64, TableIndex<M>), M> + Default,
+        F: VecStorage<T, M>,
+    {
+        let len = self.len();
+        invariant!(at <= len);
+
+        if len - at > M {
+            return Err(CapacityError::new(M, len - at));
+        }
+
+        let mut hashes = [0; M];
+        self.remove_indices(at..len, |index, hash| {
+            invariant!(index - at < M);
+            *hashes.get_unchecked_mut(index - at) = hash;
+        });
+
+        let entries = self.entries.as_mut_array_vec().remove_range::<M>(at..len);
+        let mut table = IndexTable::<T, S, M, F>::default();
+        for (entry, hash) in entries.into_iter().zip(hashes) {
+            if table.try_insert(hash, entry, |_| hash).is_err() {
+                unreachable_unchecked!("the new table has enough space for all entries");
+            }
+        }
+
+        Ok(table)
+    }
+
+    /// Removes the indices pointing to the entries in the range, without
+    /// touching the entries themselves, and updates the indices of the
+    /// following entries, so they are correct once the range has been removed
+    /// from the entries.
+    ///
+    /// The position and hash of every removed index are passed to `f`.
+    unsafe fn remove_indices(&mut self, range: Range<usize>, mut f: impl FnMut(usize, u64)) {
+        let removed = range.end - range.start;
+
+        self.indices.retain(
+            |(hash, index)| {
+                let position = index.index();
+
+                if position < range.start {
+                    true
+                } else if position >= range.end {
+                    // SAFETY: the position is at least `removed`, so this can not underflow
+                    *index = TableIndex::new(position - removed);
+                    true
+                } else {
+                    f(position, *hash);
+                    false
+                }
+            },
+            |(hash, _)| *hash,
+        );
     }
 
     /// Removes the last entry in the table if it is not empty.
     pub fn pop(&mut self, hasher: impl Fn(&T) -> u64) -> Option<T> {
-        let entry = self.entries.pop()?;
+        let entry = self.entries.as_mut_array_vec().pop()?;
         // NOTE: an entry has been removed => len = index that the last element had
-        let index = unsafe { TableIndex::new(self.entries.len()) };
+        let index = unsafe { TableIndex::new(self.entries.as_array_vec().len()) };
         let hash = hasher(&entry);
 
         unsafe {
             // this is the ident pointing to the position where the entry is in the indices
             // table
-            let ident = self.indices.find(hash, |e| index.eq(e))?;
-            self.indices.remove(ident, |idx| {
-                // the table might hash the removed entry:
-                if *idx == index {
-                    hash
-                } else {
-                    hasher(self.entries.get_unchecked(*idx))
-                }
-            })
+            let ident = self.indices.find(hash, |(_, other)| index.eq(other))?;
+            self.indices.remove(ident, |(hash, _)| *hash)
         };
 
         Some(entry)
@@ -82,26 +213,28 @@ impl<T, R: FixedSizeTable<TableIndex<N>, N>, const N: usize> IndexTable<T, R, N>
 
     #[must_use]
     pub fn get_index(&self, index: usize) -> Option<&T> {
-        // SAFETY: It is checked that the index is valid
-        unsafe {
-            if index < self.entries.len() {
-                Some(self.entries.get_unchecked(TableIndex::new(index)))
-            } else {
-                None
-            }
-        }
+        self.entries.as_array_vec().get(index)
     }
 
     #[must_use]
     pub fn get_index_mut(&mut self, index: usize) -> Option<&mut T> {
-        // SAFETY: It is checked that the index is valid
-        unsafe {
-            if index < self.entries.len() {
-                Some(self.entries.get_unchecked_mut(TableIndex::new(index)))
-            } else {
-                None
-            }
-        }
+        self.entries.as_mut_array_vec().get_mut(index)
+    }
+
+    /// Returns mutable references to the entries at the indices.
+    ///
+    /// Indices that are out of bounds result in [`UnavailableMutError::Absent`]
+    /// and repeated indices in [`UnavailableMutError::Duplicate`].
+    pub fn get_disjoint_indices_mut<const M: usize>(
+        &mut self,
+        indices: [usize; M],
+    ) -> [Result<&mut T, UnavailableMutError>; M] {
+        let len = self.entries.as_array_vec().len();
+
+        // SAFETY: it is checked that the index is valid
+        let indices = indices.map(|index| (index < len).then(|| unsafe { TableIndex::new(index) }));
+
+        self.entries.as_mut_array_vec().get_each_mut_option(indices)
     }
 
     /// Swaps the position of two entries.
@@ -122,16 +255,28 @@ impl<T, R: FixedSizeTable<TableIndex<N>, N>, const N: usize> IndexTable<T, R, N>
         // swap the indices in the indices table of the entries:
 
         // obtain the index of a
-        let a_index = *self.indices.get_unchecked(a.clone());
+        let a_index = self.indices.get_unchecked(a.clone()).1;
 
         // replace the index of b with that of a
-        let b_index = mem::replace(self.indices.get_unchecked_mut(b), a_index);
+        let b_index = mem::replace(&mut self.indices.get_unchecked_mut(b).1, a_index);
 
         // assign the index of b to a
-        *self.indices.get_unchecked_mut(a) = b_index;
+        self.indices.get_unchecked_mut(a).1 = b_index;
 
         // swap the entries in the entries vec:
-        self.entries.swap(a_index, b_index);
+        self.entries
+            .as_mut_array_vec()
+            .swap(a_index.index(), b_index.index());
+    }
+
+    /// Returns the position of the entry with the ident.
+    ///
+    /// # Safety
+    ///
+    /// The ident must be valid.
+    #[must_use]
+    pub unsafe fn index_from_ident(&self, ident: <Self as RawTable<T>>::Ident) -> TableIndex<N> {
+        self.indices.get_unchecked(ident).1
     }
 
     #[must_use]
@@ -142,7 +287,317 @@ impl<T, R: FixedSizeTable<TableIndex<N>, N>, const N: usize> IndexTable<T, R, N>
     ) -> Option<<Self as RawTable<T>>::Ident> {
         let hash = hasher(self.get_index(index.index())?);
 
-        self.indices.find(hash, |other| other == &index)
+        self.indices.find(hash, |(_, other)| other == &index)
+    }
+
+    /// Sorts the entries with the comparator function, preserving the order of
+    /// equal entries.
+    ///
+    /// # Complexity
+    ///
+    /// This completes in `O(n * log(n))` time without hashing any of the
+    /// entries.
+    pub fn sort_by(&mut self, mut cmp: impl FnMut(&T, &T) -> Ordering) {
+        // SAFETY: the positions are only sorted
+        unsafe {
+            self.reorder(|entries, positions| {
+                positions.sort_unstable_by(|&a, &b| {
+                    cmp(Self::entry_at(entries, a), Self::entry_at(entries, b)).then(a.cmp(&b))
+                });
+            });
+        }
+    }
+
+    /// Sorts the entries with the comparator function, but might not preserve
+    /// the order of equal entries.
+    ///
+    /// # Complexity
+    ///
+    /// This completes in `O(n * log(n))` time without hashing any of the
+    /// entries.
+    pub fn sort_unstable_by(&mut self, mut cmp: impl FnMut(&T, &T) -> Ordering) {
+        // SAFETY: the positions are only sorted
+        unsafe {
+            self.reorder(|entries, positions| {
+                positions.sort_unstable_by(|&a, &b| {
+                    cmp(Self::entry_at(entries, a), Self::entry_at(entries, b))
+                });
+            });
+        }
+    }
+
+    /// Sorts the entries with a key extraction function, which is called
+    /// exactly once per entry. The order of equal entries is preserved.
+    ///
+    /// # Complexity
+    ///
+    /// This completes in `O(n * log(n))` time without hashing any of the
+    /// entries.
+    pub fn sort_by_cached_key<K: Ord>(&mut self, mut f: impl FnMut(&T) -> K) {
+        // SAFETY: the positions are only sorted
+        unsafe {
+            self.reorder(|entries, positions| {
+                let keys: ArrayVec<K, N> = entries
+                    .iter()
+                    .map(&mut f)
+                    .try_collect()
+                    .unwrap_or_else(|_| unreachable_unchecked!("there are at most N entries"));
+                let keys = keys.as_slice();
+
+                positions.sort_unstable_by(|&a, &b| {
+                    Self::entry_at(keys, a)
+                        .cmp(Self::entry_at(keys, b))
+                        .then(a.cmp(&b))
+                });
+            });
+        }
+    }
+
+    /// Reverses the order of the entries.
+    pub fn reverse(&mut self) {
+        // SAFETY: the positions are only reversed
+        unsafe { self.reorder(|_, positions| positions.reverse()) };
+    }
+
+    /// Rotates the entries in-place, so that the entry at `mid` becomes the
+    /// first entry.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid` is greater than the number of entries.
+    pub fn rotate_left(&mut self, mid: usize) {
+        // SAFETY: the positions are only rotated
+        unsafe { self.reorder(|_, positions| positions.rotate_left(mid)) };
+    }
+
+    /// Rotates the entries in-place, so that the entry at `len - k` becomes the
+    /// first entry.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is greater than the number of entries.
+    pub fn rotate_right(&mut self, k: usize) {
+        // SAFETY: the positions are only rotated
+        unsafe { self.reorder(|_, positions| positions.rotate_right(k)) };
+    }
+
+    /// Moves the entry at `from` to `to`, shifting the entries in between.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from` or `to` are out of bounds.
+    pub fn move_index(&mut self, from: usize, to: usize) {
+        // SAFETY: the positions are only rotated
+        unsafe {
+            self.reorder(|_, positions| {
+                if from < to {
+                    positions[from..=to].rotate_left(1);
+                } else {
+                    positions[to..=from].rotate_right(1);
+                }
+            });
+        }
+    }
+
+    /// Binary searches the entries with the comparator function.
+    ///
+    /// See [`slice::binary_search_by`] for more.
+    pub fn binary_search_by(&self, f: impl FnMut(&T) -> Ordering) -> Result<usize, usize> {
+        self.entries.as_array_vec().as_slice().binary_search_by(f)
+    }
+
+    /// Returns the entries in their order.
+    #[must_use]
+    pub fn as_slice(&self) -> &[T] {
+        self.entries.as_array_vec().as_slice()
+    }
+
+    /// Returns the entries in their order mutably.
+    ///
+    /// # Safety
+    ///
+    /// The hashes of the entries must not change.
+    #[must_use]
+    pub unsafe fn as_mut_slice(&mut self) -> &mut [T] {
+        self.entries.as_mut_array_vec().as_mut_slice()
+    }
+
+    /// Returns the entry at the position.
+    fn entry_at<U>(entries: &[U], position: usize) -> &U {
+        // SAFETY: the positions passed to the function are always less than the length
+        unsafe {
+            invariant!(position < entries.len());
+            entries.get_unchecked(position)
+        }
+    }
+
+    /// Reorders the entries with the function `f`, which gets the entries and
+    /// their positions `0..len`, which it has to rearrange.
+    ///
+    /// Afterwards, the entry that was at `positions[i]` will be at `i`. The
+    /// entries are moved once and every index is updated once. If `f` panics,
+    /// the table is not modified.
+    ///
+    /// # Safety
+    ///
+    /// `f` must leave a permutation of the positions.
+    unsafe fn reorder(&mut self, f: impl FnOnce(&[T], &mut [usize])) {
+        let len = self.entries.as_array_vec().len();
+        let mut positions: [usize; N] = array::from_fn(|position| position);
+
+        invariant!(len <= positions.len());
+        f(
+            self.entries.as_array_vec().as_slice(),
+            positions.get_unchecked_mut(..len),
+        );
+
+        // the entries are moved out of the vec, which is therefore empty until all
+        // entries have been moved back
+        self.entries.as_mut_array_vec().set_len(0);
+        let previous = mem::replace(
+            &mut self.entries.as_mut_array_vec().data,
+            utils::uninit_array(),
+        );
+        let mut new_positions = [0; N];
+
+        for (new_position, &old_position) in positions.get_unchecked(..len).iter().enumerate() {
+            invariant!(old_position < N);
+            self.entries
+                .as_mut_array_vec()
+                .data
+                .get_unchecked_mut(new_position)
+                .write(previous.get_unchecked(old_position).assume_init_read());
+            *new_positions.get_unchecked_mut(old_position) = new_position;
+        }
+        self.entries.as_mut_array_vec().set_len(len);
+
+        for ident in self.indices.iter_idents() {
+            let (_, index) = self.indices.get_unchecked_mut(ident);
+            *index = TableIndex::new(*new_positions.get_unchecked(index.index()));
+        }
+    }
+
+    /// Starts an order preserving in-place compaction of the entries.
+    ///
+    /// Until [`IndexTable::finish_compaction`] is called, the table appears to
+    /// be empty. Not finishing the compaction is safe, but the entries that are
+    /// still in the table will be lost.
+    pub fn begin_compaction(&mut self) -> Compaction {
+        let len = self.entries.as_array_vec().len();
+
+        self.indices.clear();
+        // SAFETY: no slot has to be occupied for a length of 0
+        unsafe { self.entries.as_mut_array_vec().set_len(0) };
+
+        Compaction {
+            len,
+            read: 0,
+            write: 0,
+        }
+    }
+
+    /// Visits the entries of the compaction in their order until `f` returns
+    /// `false` for one of them, which is then removed and returned.
+    ///
+    /// The retained entries are moved to the front of the table. If `f`
+    /// panics, the entry it has been called with is retained.
+    ///
+    /// # Safety
+    ///
+    /// The compaction must have been started by
+    /// [`IndexTable::begin_compaction`] on this table and must not have
+    /// been finished yet.
+    pub unsafe fn compaction_next(
+        &mut self,
+        compaction: &mut Compaction,
+        mut f: impl FnMut(&mut T) -> bool,
+    ) -> Option<T> {
+        let data = self.entries.as_mut_array_vec().as_mut_ptr();
+
+        while compaction.read < compaction.len {
+            let read = compaction.read;
+            invariant!(read < N);
+
+            let keep = f(&mut *data.add(read));
+            compaction.read += 1;
+
+            if keep {
+                // the entry at write has either been moved or removed before
+                if compaction.write != read {
+                    ptr::copy_nonoverlapping(data.add(read), data.add(compaction.write), 1);
+                }
+                compaction.write += 1;
+            } else {
+                return Some(data.add(read).read());
+            }
+        }
+
+        None
+    }
+
+    /// Finishes the compaction by moving the unvisited entries behind the
+    /// retained ones and rebuilding the indices once.
+    ///
+    /// # Safety
+    ///
+    /// The compaction must have been started by
+    /// [`IndexTable::begin_compaction`] on this table and must not have
+    /// been finished yet.
+    ///
+    /// # Complexity
+    ///
+    /// This completes in `O(n)` time.
+    pub unsafe fn finish_compaction(
+        &mut self,
+        compaction: &Compaction,
+        hasher: impl Fn(&T) -> u64,
+    ) {
+        let removed = compaction.read - compaction.write;
+
+        // the removed entries left a gap between write and read, which is closed by
+        // moving the unvisited entries to the left:
+        invariant!(compaction.len <= N);
+        let data = self.entries.as_mut_array_vec().as_mut_ptr();
+        ptr::copy(
+            data.add(compaction.read),
+            data.add(compaction.write),
+            compaction.len - compaction.read,
+        );
+        self.entries
+            .as_mut_array_vec()
+            .set_len(compaction.len - removed);
+
+        for index in 0..self.entries.as_array_vec().len() {
+            let hash = hasher(self.entries.as_array_vec().get_unchecked(index));
+            let index = TableIndex::new(index);
+
+            // NOTE: this can not fail, because there are at most N entries
+            mem::drop(
+                self.indices
+                    .try_insert(hash, (hash, index), |(hash, _)| *hash),
+            );
+        }
+    }
+}
+
+/// The state of an in-place compaction of an [`IndexTable`].
+///
+/// See [`IndexTable::begin_compaction`].
+#[derive(Debug)]
+pub struct Compaction {
+    /// The number of entries before the compaction started.
+    len: usize,
+    /// The position of the next entry that will be visited.
+    read: usize,
+    /// The position the next retained entry will be moved to.
+    write: usize,
+}
+
+impl Compaction {
+    /// Returns the number of entries that have not been visited yet.
+    #[must_use]
+    pub const fn remaining(&self) -> usize {
+        self.len - self.read
     }
 }
 
@@ -160,47 +615,69 @@ impl<E> From<CapacityError> for IndexTableError<E> {
 
 // TODO: implement fmt::Display, Debug, Clone, ... for IndexTableError
 
-impl<T, R: FixedSizeTable<TableIndex<N>, N>, const N: usize> RawTable<T> for IndexTable<T, R, N> {
-    type DrainIter = FlatIter<T, N>;
+impl<T, R, const N: usize, E> RawTable<T> for IndexTable<T, R, N, E>
+where
+    R: FixedSizeTable<(u64, TableIndex<N>), N>,
+    E: VecStorage<T, N>,
+{
+    type DrainIter = IntoIter<T, N>;
     type Ident = R::Ident;
     type InsertError = IndexTableError<R::InsertError>;
     type RawIter = R::RawIter;
 
     fn find(&self, hash: u64, mut eq: impl FnMut(&T) -> bool) -> Option<Self::Ident> {
-        self.indices.find(hash, |index| unsafe {
-            let value = self.entries.get_unchecked(*index);
-            eq(value)
+        self.indices.find(hash, |(other_hash, index)| unsafe {
+            // the stored hash is used to skip most entries without comparing them
+            *other_hash == hash && eq(self.entries.as_array_vec().get_unchecked(index.index()))
         })
     }
 
     unsafe fn get_unchecked(&self, ident: Self::Ident) -> &T {
-        let index = self.indices.get_unchecked(ident);
-        self.entries.get_unchecked(*index)
+        let (_, index) = self.indices.get_unchecked(ident);
+        self.entries.as_array_vec().get_unchecked(index.index())
     }
 
     unsafe fn get_unchecked_mut(&mut self, ident: Self::Ident) -> &mut T {
-        let index = self.indices.get_unchecked(ident);
-        self.entries.get_unchecked_mut(*index)
+        let (_, index) = self.indices.get_unchecked(ident);
+        self.entries
+            .as_mut_array_vec()
+            .get_unchecked_mut(index.index())
     }
 
+    /// # Note
+    ///
+    /// The following entries are shifted to the left, so this completes in
+    /// `O(n)` time. Their indices are updated, so the other identifiers keep
+    /// pointing to the same entries.
     unsafe fn erase(&mut self, ident: Self::Ident) -> T {
-        let index = self.indices.erase(ident);
+        let (_, index) = self.indices.erase(ident);
+
+        let result = self
+            .entries
+            .as_mut_array_vec()
+            .remove_unchecked(index.index());
 
-        self.entries.remove_unchecked(index)
+        // all indices that have been moved must be updated:
+        self.close_gap(index);
+
+        result
     }
 
     fn try_insert(
         &mut self,
         hash: u64,
         value: T,
-        hasher: impl Fn(&T) -> u64,
+        _: impl Fn(&T) -> u64,
     ) -> Result<Self::Ident, Self::InsertError> {
-        let index = self.entries.push(value)?;
+        self.entries
+            .as_mut_array_vec()
+            .try_push(value)
+            .map_err(CapacityError::from)?;
+        // SAFETY: the entry has just been pushed, so its index is less than N
+        let index = unsafe { TableIndex::new(self.entries.as_array_vec().len() - 1) };
         let ident = self
             .indices
-            .try_insert(hash, index, |ident| unsafe {
-                hasher(self.entries.get_unchecked(*ident))
-            })
+            .try_insert(hash, (hash, index), |(hash, _)| *hash)
             .map_err(Self::InsertError::Table)?;
         Ok(ident)
     }
@@ -214,41 +691,80 @@ impl<T, R: FixedSizeTable<TableIndex<N>, N>, const N: usize> RawTable<T> for Ind
     /// This does not preserve the order of the removed value.
     /// Use `shift_remove` if the order should be preserved!
     unsafe fn remove(&mut self, ident: Self::Ident, hasher: impl Fn(&T) -> u64) -> T {
-        invariant!(!self.entries.is_empty());
+        invariant!(!self.entries.as_array_vec().is_empty());
 
-        let last_index = TableIndex::new(self.entries.len() - 1);
-        let last_hash = hasher(self.entries.get_unchecked(last_index));
+        let last_index = TableIndex::new(self.entries.as_array_vec().len() - 1);
+        let last_hash = hasher(
+            self.entries
+                .as_array_vec()
+                .get_unchecked(last_index.index()),
+        );
 
         let last_index_ident = self
             .indices
-            .find(last_hash, |other| other == &last_index)
+            .find(last_hash, |(_, other)| other == &last_index)
             .expect_unchecked("the last index should be present");
 
-        self.swap(ident, last_index_ident);
+        self.swap(ident.clone(), last_index_ident);
 
-        // remove the ident from the indices table (now at the last_index!)
-
-        // first get the ident of the table:
-        let ident = self
-            .indices
-            .find(hasher(self.entries.get_unchecked(last_index)), |other| {
-                other == &last_index
-            })
-            .expect_unchecked("ident must exist");
-
-        self.indices.remove(ident, |ident| unsafe {
-            hasher(self.entries.get_unchecked(*ident))
-        });
+        // remove the ident from the indices table (it now points to the last_index!)
+        self.indices.remove(ident, |(hash, _)| *hash);
 
         self.entries
+            .as_mut_array_vec()
             .pop()
             .expect_unchecked("table can not be empty")
     }
 
+    /// # Note
+    ///
+    /// The entries are visited in their order and the order of the retained
+    /// entries is preserved. The indices are only rebuilt once.
+    fn retain(&mut self, mut f: impl FnMut(&mut T) -> bool, hasher: impl Fn(&T) -> u64) {
+        struct FinishGuard<'a, T, R, E, H, const N: usize>
+        where
+            R: FixedSizeTable<(u64, TableIndex<N>), N>,
+            E: VecStorage<T, N>,
+            H: Fn(&T) -> u64,
+        {
+            table: &'a mut IndexTable<T, R, N, E>,
+            compaction: Compaction,
+            hasher: H,
+        }
+
+        impl<'a, T, R, E, H, const N: usize> Drop for FinishGuard<'a, T, R, E, H, N>
+        where
+            R: FixedSizeTable<(u64, TableIndex<N>), N>,
+            E: VecStorage<T, N>,
+            H: Fn(&T) -> u64,
+        {
+            fn drop(&mut self) {
+                // SAFETY: the compaction has been started on the table and is only
+                //         finished here
+                unsafe { self.table.finish_compaction(&self.compaction, &self.hasher) };
+            }
+        }
+
+        let compaction = self.begin_compaction();
+        let mut guard = FinishGuard {
+            table: self,
+            compaction,
+            hasher,
+        };
+
+        // SAFETY: the compaction has been started on the table and is finished by the
+        //         guard
+        while let Some(entry) =
+            unsafe { guard.table.compaction_next(&mut guard.compaction, &mut f) }
+        {
+            mem::drop(entry);
+        }
+    }
+
     fn drain(&mut self) -> Self::DrainIter {
         self.indices.clear();
 
-        self.entries.drain()
+        self.entries.as_mut_array_vec().take().into_iter()
     }
 
     fn capacity(&self) -> usize {
@@ -256,7 +772,11 @@ impl<T, R: FixedSizeTable<TableIndex<N>, N>, const N: usize> RawTable<T> for Ind
     }
 
     fn len(&self) -> usize {
-        self.entries.len()
+        self.entries.as_array_vec().len()
+    }
+
+    fn probe_length(&self, ident: &Self::Ident, hash: u64) -> usize {
+        self.indices.probe_length(ident, hash)
     }
 
     // TODO: does this even work?
@@ -267,63 +787,110 @@ impl<T, R: FixedSizeTable<TableIndex<N>, N>, const N: usize> RawTable<T> for Ind
     ) -> [Result<&mut T, UnavailableMutError>; M] {
         let indices = hashes.enumerate().map(|(i, hash)| {
             self.indices
-                .get(hash, |ident| unsafe {
-                    let value = self.entries.get_unchecked(*ident);
+                .get(hash, |(_, index)| unsafe {
+                    let value = self.entries.as_array_vec().get_unchecked(index.index());
                     eq(i, value)
                 })
-                .copied()
+                .map(|(_, index)| *index)
         });
 
-        self.entries.get_each_mut_option(indices)
+        self.entries.as_mut_array_vec().get_each_mut_option(indices)
     }
 }
 
-impl<T, R: FixedSizeTable<TableIndex<N>, N>, const N: usize> IntoIterator for IndexTable<T, R, N> {
-    // TODO: custom iterator
-    type IntoIter = iter::Flatten<array::IntoIter<Option<T>, N>>;
+impl<T, R, const N: usize, E> IntoIterator for IndexTable<T, R, N, E>
+where
+    R: FixedSizeTable<(u64, TableIndex<N>), N>,
+    E: VecStorage<T, N>,
+{
+    type IntoIter = IntoIter<T, N>;
     type Item = T;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.entries.into_iter()
+        self.entries.into_array_vec().into_iter()
     }
 }
 
-impl<T, R: FixedSizeTable<TableIndex<N>, N>, const N: usize> FixedSizeTable<T, N>
-    for IndexTable<T, R, N>
+impl<T, R, const N: usize, E> FixedSizeTable<T, N> for IndexTable<T, R, N, E>
+where
+    R: FixedSizeTable<(u64, TableIndex<N>), N>,
+    E: VecStorage<T, N>,
 {
 }
 
-impl<T, R: FixedSizeTable<TableIndex<N>, N>, const N: usize> RawTableIter<T>
-    for IndexTable<T, R, N>
+#[cfg(feature = "rayon")]
+impl<T, R, const N: usize, E> SlotTable<T> for IndexTable<T, R, N, E>
+where
+    R: FixedSizeTable<(u64, TableIndex<N>), N>,
+    E: VecStorage<T, N>,
+{
+    type Slot = T;
+    type Slots = [Option<T>; N];
+
+    fn slots(&self) -> &[T] {
+        self.entries.as_array_vec().as_slice()
+    }
+
+    unsafe fn slots_mut(&mut self) -> &mut [T] {
+        self.entries.as_mut_array_vec().as_mut_slice()
+    }
+
+    fn take_slots(&mut self) -> Self::Slots {
+        let mut entries = self.drain();
+
+        array::from_fn(|_| entries.next())
+    }
+
+    fn retain_slots(&mut self, keep: &[bool], hasher: impl Fn(&T) -> u64) {
+        let mut position = 0;
+
+        self.retain(
+            |_| {
+                position += 1;
+                keep[position - 1]
+            },
+            hasher,
+        );
+    }
+}
+
+impl<T, R, const N: usize, E> RawTableIter<T> for IndexTable<T, R, N, E>
+where
+    R: FixedSizeTable<(u64, TableIndex<N>), N>,
+    E: VecStorage<T, N>,
 {
-    type Iter<'a> = FlatIter<&'a T, N>
+    type Iter<'a> = slice::Iter<'a, T>
     where
         T: 'a,
-        R: 'a;
-    type IterMut<'a> = IterMut<'a, T>
+        R: 'a,
+        E: 'a;
+    type IterMut<'a> = slice::IterMut<'a, T>
     where
         T: 'a,
-        R: 'a;
+        R: 'a,
+        E: 'a;
 
     /// Returns a mutable iterator over the table.
     fn iter_mut(&mut self) -> Self::IterMut<'_> {
-        IterMut::new(self.entries.data.iter_mut())
+        self.entries.as_mut_array_vec().as_mut_slice().iter_mut()
     }
 
     /// Returns an immutable iterator over the table.
     fn iter(&self) -> Self::Iter<'_> {
-        FlatIter::new(self.entries.data.each_ref().map(Option::as_ref))
+        self.entries.as_array_vec().as_slice().iter()
     }
 }
 
-impl<T, R, const N: usize> Default for IndexTable<T, R, N>
+impl<T, R, const N: usize, E> Default for IndexTable<T, R, N, E>
 where
-    R: FixedSizeTable<TableIndex<N>, N> + Default,
+    R: FixedSizeTable<(u64, TableIndex<N>), N> + Default,
+    E: VecStorage<T, N>,
 {
     fn default() -> Self {
         Self {
             indices: R::default(),
-            entries: ArrayVec::default(),
+            entries: E::default(),
+            _p: PhantomData,
         }
     }
 }
@@ -331,12 +898,13 @@ where
 #[cfg(test)]
 impl<T, R, const N: usize> From<(R, ArrayVec<T, N>)> for IndexTable<T, R, N>
 where
-    R: FixedSizeTable<TableIndex<N>, N>,
+    R: FixedSizeTable<(u64, TableIndex<N>), N>,
 {
     fn from(value: (R, ArrayVec<T, N>)) -> Self {
         Self {
             indices: value.0,
             entries: value.1,
+            _p: PhantomData,
         }
     }
 }
@@ -429,7 +997,7 @@ mod tests {
         for value in [Value::Bar, Value::Foo] {
             let hash = Value::hasher(&value);
             let ident = table.find(hash, |v| v.eq(&value)).unwrap();
-            assert_eq!(unsafe { table.shift_remove(ident, Value::hasher) }, value);
+            assert_eq!(unsafe { table.shift_remove(ident) }, value);
         }
 
         // check that the order is still correct:
@@ -469,18 +1037,19 @@ mod tests {
                 indices: ArrayTable::from_array([
                     None,
                     // "foo"
-                    Some(unsafe { TableIndex::new(1) }),
+                    Some((1, unsafe { TableIndex::new(1) })),
                     // "bar"
-                    Some(unsafe { TableIndex::new(3) }),
+                    Some((2, unsafe { TableIndex::new(3) })),
                     // "avc"
-                    Some(unsafe { TableIndex::new(0) }),
+                    Some((3, unsafe { TableIndex::new(0) })),
                     // "baz"
-                    Some(unsafe { TableIndex::new(2) }),
+                    Some((4, unsafe { TableIndex::new(2) })),
                 ]),
                 entries: ["avc", "foo", "baz", "bar"]
                     .into_iter()
                     .try_collect()
                     .unwrap(),
+                _p: PhantomData,
             }
         );
 
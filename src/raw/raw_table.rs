@@ -1,3 +1,4 @@
+use core::marker::PhantomData;
 use core::mem;
 
 use crate::ext::ToIter;
@@ -125,8 +126,58 @@ pub trait RawTable<T>: IntoIterator<Item = T> {
         Some(unsafe { self.get_unchecked_mut(ident) })
     }
 
+    /// Returns the number of slots between the slot where the probing for
+    /// `hash` starts and the slot of the entry with the provided identifier.
+    ///
+    /// Tables that do not probe for entries always return `0`.
+    #[must_use]
+    fn probe_length(&self, ident: &Self::Ident, hash: u64) -> usize {
+        let _ = (ident, hash);
+        0
+    }
+
+    /// Removes all entries for which `f` returns `false`.
+    ///
+    /// The default implementation erases the entries and rehashes the table
+    /// afterwards, even if `f` panics.
+    fn retain(&mut self, mut f: impl FnMut(&mut T) -> bool, hasher: impl Fn(&T) -> u64)
+    where
+        Self: Sized,
+    {
+        struct RehashGuard<'a, T, R: RawTable<T>, H: Fn(&T) -> u64> {
+            table: &'a mut R,
+            hasher: H,
+            _p: PhantomData<T>,
+        }
+
+        impl<'a, T, R: RawTable<T>, H: Fn(&T) -> u64> Drop for RehashGuard<'a, T, R, H> {
+            fn drop(&mut self) {
+                self.table.rehash(&self.hasher);
+            }
+        }
+
+        let idents = self.iter_idents();
+        let guard = RehashGuard {
+            table: self,
+            hasher,
+            _p: PhantomData,
+        };
+
+        for ident in idents {
+            // SAFETY: the idents are only invalidated by `remove`, `erase` only makes
+            //         entries unreachable through `find`
+            unsafe {
+                if !f(guard.table.get_unchecked_mut(ident.clone())) {
+                    mem::drop(guard.table.erase(ident));
+                }
+            }
+        }
+    }
+
     /// This method is used to rediscover lost entries (can be caused by
     /// `erase`) in the table.
+    ///
+    /// It is also used to rebuild the table after the hasher changed.
     fn rehash(&mut self, hasher: impl Fn(&T) -> u64) {
         for entry in self.drain() {
             mem::drop(self.try_insert(hasher(&entry), entry, |value| hasher(value)));
@@ -153,3 +204,71 @@ pub trait RawTableIter<T>: RawTable<T> {
 
 /// A trait implemented by [`RawTable`]s with a fixed capacity.
 pub trait FixedSizeTable<T, const N: usize>: RawTable<T> {}
+
+/// A trait implemented by [`RawTable`]s that store their entries in an array
+/// of slots, so the entries can be split into chunks (for example to process
+/// them in parallel).
+#[cfg(feature = "rayon")]
+pub trait SlotTable<T>: RawTable<T> {
+    /// A slot of the table.
+    type Slot: TableSlot<T>;
+    /// The owned slots of the table.
+    type Slots: IntoIterator<Item = Option<T>>;
+
+    /// Returns the slots of the table.
+    #[must_use]
+    fn slots(&self) -> &[Self::Slot];
+
+    /// Returns the slots of the table mutably.
+    ///
+    /// # Safety
+    ///
+    /// Slots must not be vacated or filled with new entries and the hashes of
+    /// the entries must not change.
+    #[must_use]
+    unsafe fn slots_mut(&mut self) -> &mut [Self::Slot];
+
+    /// Removes all entries from the table and returns its slots.
+    #[must_use]
+    fn take_slots(&mut self) -> Self::Slots;
+
+    /// Removes the entries of all slots for which `keep` is `false`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `keep` has less elements than the table has slots.
+    fn retain_slots(&mut self, keep: &[bool], hasher: impl Fn(&T) -> u64);
+}
+
+/// A slot of a [`SlotTable`], which might be vacant.
+#[cfg(feature = "rayon")]
+pub trait TableSlot<T> {
+    /// Returns the entry in the slot or `None` if the slot is vacant.
+    fn get(&self) -> Option<&T>;
+
+    /// Returns the entry in the slot mutably or `None` if the slot is vacant.
+    fn get_mut(&mut self) -> Option<&mut T>;
+}
+
+#[cfg(feature = "rayon")]
+impl<T> TableSlot<T> for Option<T> {
+    fn get(&self) -> Option<&T> {
+        self.as_ref()
+    }
+
+    fn get_mut(&mut self) -> Option<&mut T> {
+        self.as_mut()
+    }
+}
+
+/// Tables that store their entries contiguously do not have vacant slots.
+#[cfg(feature = "rayon")]
+impl<T> TableSlot<T> for T {
+    fn get(&self) -> Option<&T> {
+        Some(self)
+    }
+
+    fn get_mut(&mut self) -> Option<&mut T> {
+        Some(self)
+    }
+}
@@ -1,21 +1,46 @@
 use core::borrow::Borrow;
-use core::hash::{BuildHasher, Hash};
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{BuildHasher, Hash, Hasher};
+use core::ops::{BitAnd, BitOr, BitXor, Sub};
 
-use crate::map::iter::MapLeftIter;
-use crate::raw::{ArrayTable, RawTable, RawTableIter};
+use crate::ext::{IteratorExt, TryExtend, TryFromIterator};
+use crate::map::iter::{self as map_iter, Keys, MapLeftIter};
+use crate::raw::{ArrayTable, FixedSizeTable, RawTable, RawTableIter};
+use crate::set::iter::DrainFilter;
 use crate::set::{Set, SetIter};
-use crate::{ArrayMapFacade, CapacityError, DefaultHashBuilder};
+use crate::{
+    unreachable_unchecked, ArrayMapFacade, ArrayVec, CapacityError, DefaultHashBuilder,
+    RescaleError,
+};
 
 pub type ArraySet<T, const N: usize, B = DefaultHashBuilder> =
     ArraySetFacade<T, ArrayTable<(T, ()), N>, B>;
 
+/// Returns the capacity of the set returned by
+/// [`ArraySet::intersection_into_smaller`] for sets with the capacities `n`
+/// and `m`.
+#[cfg(feature = "generic_const_exprs")]
+#[must_use]
+pub const fn intersection_capacity(n: usize, m: usize) -> usize {
+    if n < m {
+        n
+    } else {
+        m
+    }
+}
+
 #[derive(Copy, Clone)]
 pub struct ArraySetFacade<T, R: RawTable<(T, ())>, B = DefaultHashBuilder> {
-    map: ArrayMapFacade<T, (), R, B>,
+    pub(crate) map: ArrayMapFacade<T, (), R, B>,
 }
 
 pub type IntoIter<T, R> = MapLeftIter<(T, ()), <R as IntoIterator>::IntoIter>;
 
+pub type Iter<'a, T, R> = Keys<'a, T, (), R>;
+
+pub type Drain<'a, T, R, B> = MapLeftIter<(T, ()), map_iter::Drain<'a, T, (), R, B>>;
+
 #[cfg(feature = "ahash")]
 impl<T, R: RawTable<(T, ())> + Default> ArraySetFacade<T, R, DefaultHashBuilder> {
     /// Creates an empty `ArraySetFacade` with the [`DefaultHashBuilder`].
@@ -35,6 +60,50 @@ impl<T, R: RawTable<(T, ())> + Default> ArraySetFacade<T, R, DefaultHashBuilder>
     }
 }
 
+impl<T, R: RawTable<(T, ())> + Default, B: BuildHasher> ArraySetFacade<T, R, B> {
+    /// Creates an empty [`ArraySetFacade`] with the provided [`BuildHasher`].
+    ///
+    /// # Note
+    ///
+    /// This is function is identical to [`ArraySetFacade::with_build_hasher`],
+    /// it exists for API compatibility with the standard librarys `HashSet`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::set::{ArraySet, DefaultHashBuilder};
+    ///
+    /// let set: ArraySet<usize, 31, DefaultHashBuilder> =
+    ///     ArraySet::with_hasher(DefaultHashBuilder::default());
+    /// assert_eq!(set.is_empty(), true);
+    /// ```
+    #[must_use]
+    #[doc(alias("with_build_hasher"))]
+    pub fn with_hasher(build_hasher: B) -> Self {
+        Self::with_build_hasher(build_hasher)
+    }
+
+    /// Creates an empty [`ArraySetFacade`] with the provided [`BuildHasher`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::set::{ArraySet, DefaultHashBuilder, Set};
+    ///
+    /// let build_hasher = DefaultHashBuilder::default();
+    /// let set: ArraySet<usize, 31, DefaultHashBuilder> =
+    ///     ArraySet::with_build_hasher(build_hasher);
+    /// assert_eq!(set.is_empty(), true);
+    /// ```
+    #[must_use]
+    #[doc(alias("with_hasher"))]
+    pub fn with_build_hasher(build_hasher: B) -> Self {
+        Self {
+            map: ArrayMapFacade::with_build_hasher(build_hasher),
+        }
+    }
+}
+
 impl<T, R: RawTable<(T, ())>, B: BuildHasher> ArraySetFacade<T, R, B> {
     /// Returns the number of elements the set can hold in total.
     /// The returned value will be equal to the const generic `N`.
@@ -51,6 +120,341 @@ impl<T, R: RawTable<(T, ())>, B: BuildHasher> ArraySetFacade<T, R, B> {
     pub fn capacity(&self) -> usize {
         self.map.capacity()
     }
+
+    /// Returns the number of elements in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::array_set;
+    ///
+    /// let set = array_set!(1, 2);
+    /// assert_eq!(set.len(), 2);
+    /// ```
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the set contains no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::set::ArraySet;
+    ///
+    /// let set: ArraySet<u8, 2> = ArraySet::new();
+    /// assert!(set.is_empty());
+    /// ```
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+}
+
+impl<T, R, B> ArraySetFacade<T, R, B>
+where
+    T: Hash + Eq,
+    R: RawTable<(T, ())>,
+    B: BuildHasher,
+{
+    /// Returns `true` if the set contains the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::array_set;
+    ///
+    /// let set = array_set!(1, 2);
+    /// assert_eq!(set.contains(&1), true);
+    /// assert_eq!(set.contains(&4), false);
+    /// ```
+    #[must_use]
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.contains_key(value)
+    }
+
+    /// Clears the set, removing all values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::array_set;
+    ///
+    /// let mut set = array_set!(1, 2, 3);
+    /// set.clear();
+    /// assert!(set.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    /// Clears the set, returning all values in an iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::array_set;
+    ///
+    /// let mut set = array_set!(1, 2, 3);
+    ///
+    /// let mut drained = set.drain().collect::<Vec<_>>();
+    /// drained.sort_unstable();
+    ///
+    /// assert_eq!(drained, vec![1, 2, 3]);
+    /// assert!(set.is_empty());
+    /// ```
+    pub fn drain(&mut self) -> Drain<'_, T, R, B> {
+        MapLeftIter::new(self.map.drain())
+    }
+
+    /// Creates an iterator which uses a closure to determine if a value
+    /// should be removed.
+    ///
+    /// If the closure returns `true`, the value is removed from the set and
+    /// yielded. If the closure returns `false`, or panics, the value remains
+    /// in the set and will not be yielded.
+    ///
+    /// If the iterator is only partially consumed or not consumed at all, each
+    /// of the remaining values will still be subjected to the closure and
+    /// removed and dropped if it returns true.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::array_set;
+    ///
+    /// let mut set = array_set!(1, 2, 3, 4, 5, 6);
+    ///
+    /// let mut evens = set.drain_filter(|value| value % 2 == 0).collect::<Vec<_>>();
+    /// evens.sort_unstable();
+    ///
+    /// assert_eq!(evens, vec![2, 4, 6]);
+    /// assert_eq!(set.len(), 3);
+    /// ```
+    pub fn drain_filter<F>(
+        &mut self,
+        mut f: F,
+    ) -> DrainFilter<'_, T, impl FnMut(&T, &mut ()) -> bool, R, B>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        MapLeftIter::new(self.map.drain_filter(move |value, ()| f(value)))
+    }
+
+    /// Retains only the values specified by the predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::array_set;
+    ///
+    /// let mut set = array_set!(1, 2, 3, 4, 5, 6);
+    /// set.retain(|value| value % 2 == 0);
+    ///
+    /// assert_eq!(set.len(), 3);
+    /// assert!(set.contains(&4));
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.drain_filter(|value| !f(value));
+    }
+
+    /// Tries to move the values into a set with the capacity `M`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RescaleError`] if the values do not fit into the new set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::array_set;
+    /// use array_map::set::ArraySet;
+    ///
+    /// let set = array_set!('a');
+    ///
+    /// let rescaled: ArraySet<_, 3> = set.try_rescale().expect("failed to rescale");
+    /// assert_eq!(rescaled.capacity(), 3);
+    /// assert!(rescaled.contains(&'a'));
+    /// ```
+    pub fn try_rescale<Q, const M: usize>(
+        self,
+    ) -> Result<ArraySetFacade<T, Q, B>, RescaleError<M>>
+    where
+        Q: FixedSizeTable<(T, ()), M> + Default,
+    {
+        Ok(ArraySetFacade {
+            map: self.map.try_rescale()?,
+        })
+    }
+}
+
+impl<T, R, B> ArraySetFacade<T, R, B>
+where
+    T: Hash + Eq,
+    R: RawTableIter<(T, ())>,
+    B: BuildHasher,
+{
+    /// An iterator visiting all values in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::array_set;
+    ///
+    /// let set = array_set!("a", "b");
+    ///
+    /// let mut values = set.iter().collect::<Vec<_>>();
+    /// values.sort_unstable();
+    /// assert_eq!(values, vec![&"a", &"b"]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T, R> {
+        self.map.keys()
+    }
+}
+
+#[cfg(feature = "generic_const_exprs")]
+impl<T, B, const N: usize> ArraySet<T, N, B>
+where
+    T: Hash + Eq,
+    B: BuildHasher,
+{
+    /// Returns the union of this set and `other` in a set that is large
+    /// enough to hold the values of both sets, so it can not fail.
+    ///
+    /// Like [`ArrayMap::merge_into_larger`], this requires the
+    /// `generic_const_exprs` feature of this crate and in the calling crate.
+    ///
+    /// [`ArrayMap::merge_into_larger`]: crate::ArrayMap::merge_into_larger
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![allow(incomplete_features)]
+    /// #![feature(generic_const_exprs)]
+    /// use array_map::set::{ArraySet, Set};
+    ///
+    /// let mut a: ArraySet<_, 2> = ArraySet::new();
+    /// a.try_insert(1)?;
+    /// a.try_insert(2)?;
+    ///
+    /// let mut b: ArraySet<_, 3> = ArraySet::new();
+    /// b.try_insert(2)?;
+    /// b.try_insert(3)?;
+    ///
+    /// let union: ArraySet<_, 5> = a.union_into_larger(b);
+    ///
+    /// assert_eq!(union.len(), 3);
+    /// assert!(union.contains(&1) && union.contains(&2) && union.contains(&3));
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// O(n + m)
+    #[must_use]
+    pub fn union_into_larger<C, const M: usize>(
+        self,
+        other: ArraySet<T, M, C>,
+    ) -> ArraySet<T, { N + M }, B>
+    where
+        [(); N + M]:,
+    {
+        ArraySetFacade {
+            map: self.map.merge_into_larger(other.map),
+        }
+    }
+
+    /// Returns the intersection of this set and `other` in a set with the
+    /// capacity of the smaller set, which is always large enough to hold
+    /// the intersection.
+    ///
+    /// Like [`ArrayMap::merge_into_larger`], this requires the
+    /// `generic_const_exprs` feature of this crate and in the calling crate.
+    ///
+    /// [`ArrayMap::merge_into_larger`]: crate::ArrayMap::merge_into_larger
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![allow(incomplete_features)]
+    /// #![feature(generic_const_exprs)]
+    /// use array_map::set::{ArraySet, Set};
+    ///
+    /// let mut a: ArraySet<_, 4> = ArraySet::new();
+    /// a.try_insert(1)?;
+    /// a.try_insert(2)?;
+    /// a.try_insert(3)?;
+    ///
+    /// let mut b: ArraySet<_, 2> = ArraySet::new();
+    /// b.try_insert(2)?;
+    /// b.try_insert(3)?;
+    ///
+    /// let intersection: ArraySet<_, 2> = a.intersection_into_smaller(&b);
+    ///
+    /// assert_eq!(intersection.len(), 2);
+    /// assert!(intersection.contains(&2) && intersection.contains(&3));
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// O(n)
+    #[must_use]
+    pub fn intersection_into_smaller<C, const M: usize>(
+        mut self,
+        other: &ArraySet<T, M, C>,
+    ) -> ArraySet<T, { intersection_capacity(N, M) }, B>
+    where
+        C: BuildHasher,
+        [(); intersection_capacity(N, M)]:,
+    {
+        let mut map = ArrayMapFacade::with_build_hasher(self.map.build_hasher);
+
+        for (value, ()) in self.map.table.drain() {
+            if other.contains(&value) {
+                if let Err(error) = map.try_insert(value, ()) {
+                    unreachable_unchecked!("{}", error);
+                }
+            }
+        }
+
+        ArraySetFacade { map }
+    }
+}
+
+#[cfg(feature = "panic")]
+impl<T: Hash + Eq, R: RawTable<(T, ())>, B: BuildHasher> ArraySetFacade<T, R, B> {
+    /// Adds a value to the set.
+    ///
+    /// Returns whether the value was newly inserted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is not present in the set and the set is already
+    /// full. Use [`Set::try_insert`] to handle this case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::set::ArraySet;
+    ///
+    /// let mut set: ArraySet<i32, 2> = ArraySet::new();
+    ///
+    /// assert_eq!(set.insert(2), true);
+    /// assert_eq!(set.insert(2), false);
+    /// ```
+    #[track_caller]
+    pub fn insert(&mut self, value: T) -> bool {
+        self.map.insert(value, ()).is_none()
+    }
 }
 
 impl<T: Hash + Eq, R: RawTable<(T, ())>, B: BuildHasher> Set<T> for ArraySetFacade<T, R, B> {
@@ -101,7 +505,7 @@ where
     R: RawTableIter<(T, ())>,
     B: BuildHasher,
 {
-    type Iter<'a> = crate::map::iter::Keys<'a, T, (), R>
+    type Iter<'a> = Iter<'a, T, R>
     where
         T: 'a,
         R: 'a,
@@ -112,6 +516,20 @@ where
     }
 }
 
+impl<'a, T, R, B> IntoIterator for &'a ArraySetFacade<T, R, B>
+where
+    T: Hash + Eq,
+    R: RawTableIter<(T, ())>,
+    B: BuildHasher,
+{
+    type IntoIter = Iter<'a, T, R>;
+    type Item = &'a T;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 impl<T, R, B> IntoIterator for ArraySetFacade<T, R, B>
 where
     T: Hash + Eq,
@@ -126,6 +544,356 @@ where
     }
 }
 
+impl<T, R, B> fmt::Debug for ArraySetFacade<T, R, B>
+where
+    T: fmt::Debug,
+    R: RawTableIter<(T, ())>,
+    B: BuildHasher,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.map.keys()).finish()
+    }
+}
+
+impl<T, R, B> PartialEq for ArraySetFacade<T, R, B>
+where
+    T: Eq + Hash,
+    R: RawTableIter<(T, ())>,
+    B: BuildHasher,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.map == other.map
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, R, B, S> PartialEq<::std::collections::HashSet<T, S>> for ArraySetFacade<T, R, B>
+where
+    T: Eq + Hash,
+    R: RawTableIter<(T, ())>,
+    B: BuildHasher,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &::std::collections::HashSet<T, S>) -> bool {
+        self.set_eq(other)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, R, B, S> PartialEq<ArraySetFacade<T, R, B>> for ::std::collections::HashSet<T, S>
+where
+    T: Eq + Hash,
+    R: RawTableIter<(T, ())>,
+    B: BuildHasher,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &ArraySetFacade<T, R, B>) -> bool {
+        self.set_eq(other)
+    }
+}
+
+impl<T, R, B> Eq for ArraySetFacade<T, R, B>
+where
+    T: Eq + Hash,
+    R: RawTableIter<(T, ())>,
+    B: BuildHasher,
+{
+}
+
+impl<T, R, B> ArraySetFacade<T, R, B>
+where
+    T: Ord,
+    R: RawTableIter<(T, ())>,
+    B: BuildHasher,
+{
+    /// Returns references to the values in ascending order.
+    ///
+    /// The references are sorted on the stack, so this does not require an
+    /// allocation.
+    ///
+    /// # Panics
+    ///
+    /// If the set has more than `N` values.
+    pub(crate) fn sorted<const N: usize>(&self) -> ArrayVec<&T, N> {
+        let mut result: ArrayVec<&T, N> = self
+            .map
+            .keys()
+            .try_collect()
+            .expect("the set has more than `N` values");
+
+        result.sort_unstable();
+        result
+    }
+}
+
+/// Sets are compared by their values in ascending order.
+///
+/// # Complexity
+///
+/// O(n log n)
+impl<T, B, const N: usize> PartialOrd for ArraySet<T, N, B>
+where
+    T: Hash + Ord,
+    B: BuildHasher,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T, B, const N: usize> Ord for ArraySet<T, N, B>
+where
+    T: Hash + Ord,
+    B: BuildHasher,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.sorted::<N>().cmp(&other.sorted::<N>())
+    }
+}
+
+impl<T, R, B> Hash for ArraySetFacade<T, R, B>
+where
+    T: Eq + Hash,
+    R: RawTableIter<(T, ())>,
+    B: BuildHasher,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.map.hash(state);
+    }
+}
+
+impl<T, R, B> TryFromIterator<T> for ArraySetFacade<T, R, B>
+where
+    T: Hash + Eq,
+    R: RawTable<(T, ())> + Default,
+    B: BuildHasher + Default,
+{
+    type Error = CapacityError;
+
+    fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, Self::Error> {
+        Ok(Self {
+            map: iter.into_iter().map(|value| (value, ())).try_collect()?,
+        })
+    }
+}
+
+impl<T, R, B> TryExtend<T> for ArraySetFacade<T, R, B>
+where
+    T: Hash + Eq,
+    R: RawTable<(T, ())>,
+    B: BuildHasher,
+{
+    type Error = CapacityError;
+
+    fn try_extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), Self::Error> {
+        self.map.try_extend(iter.into_iter().map(|value| (value, ())))
+    }
+}
+
+impl<'a, T, R, B> TryExtend<&'a T> for ArraySetFacade<T, R, B>
+where
+    T: Hash + Eq + Copy,
+    R: RawTable<(T, ())>,
+    B: BuildHasher,
+{
+    type Error = CapacityError;
+
+    fn try_extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) -> Result<(), Self::Error> {
+        self.try_extend(iter.into_iter().copied())
+    }
+}
+
+impl<T, R, B> ArraySetFacade<T, R, B>
+where
+    T: Hash + Eq,
+    R: RawTable<(T, ())> + Default,
+    B: BuildHasher + Default,
+{
+    /// Collects values into a set, which is known to be large enough to hold
+    /// all of them.
+    fn collect_subset<'a, I>(iter: I) -> Self
+    where
+        T: Clone + 'a,
+        I: Iterator<Item = &'a T>,
+    {
+        let mut result = Self::default();
+
+        for value in iter {
+            if let Err(error) = result.map.try_insert(value.clone(), ()) {
+                unreachable_unchecked!("{}", error);
+            }
+        }
+
+        result
+    }
+}
+
+impl<T, R, B> BitOr<&ArraySetFacade<T, R, B>> for &ArraySetFacade<T, R, B>
+where
+    T: Hash + Eq + Clone,
+    R: RawTableIter<(T, ())> + Default,
+    B: BuildHasher + Default,
+{
+    type Output = Result<ArraySetFacade<T, R, B>, CapacityError>;
+
+    /// Returns the union of `self` and `rhs` as a new set.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CapacityError`] if the union does not fit into a set with
+    /// the same capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::array_set;
+    /// use array_map::set::ArraySet;
+    ///
+    /// let a: ArraySet<_, 4> = array_set!(@capacity 4, 1, 2, 3);
+    /// let b: ArraySet<_, 4> = array_set!(@capacity 4, 3, 4);
+    ///
+    /// let union = (&a | &b)?;
+    /// assert_eq!(union.len(), 4);
+    ///
+    /// let c: ArraySet<_, 4> = array_set!(@capacity 4, 5);
+    /// assert!((&union | &c).is_err());
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    fn bitor(self, rhs: &ArraySetFacade<T, R, B>) -> Self::Output {
+        self.union(rhs).cloned().try_collect()
+    }
+}
+
+impl<T, R, B> BitAnd<&ArraySetFacade<T, R, B>> for &ArraySetFacade<T, R, B>
+where
+    T: Hash + Eq + Clone,
+    R: RawTableIter<(T, ())> + Default,
+    B: BuildHasher + Default,
+{
+    type Output = ArraySetFacade<T, R, B>;
+
+    /// Returns the intersection of `self` and `rhs` as a new set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::array_set;
+    /// use array_map::set::ArraySet;
+    ///
+    /// let a: ArraySet<_, 3> = array_set!(1, 2, 3);
+    /// let b: ArraySet<_, 3> = array_set!(2, 3, 4);
+    ///
+    /// let intersection = &a & &b;
+    /// assert_eq!(intersection.len(), 2);
+    /// assert!(intersection.contains(&2) && intersection.contains(&3));
+    /// ```
+    fn bitand(self, rhs: &ArraySetFacade<T, R, B>) -> Self::Output {
+        ArraySetFacade::collect_subset(self.intersection(rhs))
+    }
+}
+
+impl<T, R, B> Sub<&ArraySetFacade<T, R, B>> for &ArraySetFacade<T, R, B>
+where
+    T: Hash + Eq + Clone,
+    R: RawTableIter<(T, ())> + Default,
+    B: BuildHasher + Default,
+{
+    type Output = ArraySetFacade<T, R, B>;
+
+    /// Returns the difference of `self` and `rhs` as a new set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::array_set;
+    /// use array_map::set::ArraySet;
+    ///
+    /// let a: ArraySet<_, 3> = array_set!(1, 2, 3);
+    /// let b: ArraySet<_, 3> = array_set!(2, 3, 4);
+    ///
+    /// let difference = &a - &b;
+    /// assert_eq!(difference.iter().collect::<Vec<_>>(), vec![&1]);
+    /// ```
+    fn sub(self, rhs: &ArraySetFacade<T, R, B>) -> Self::Output {
+        ArraySetFacade::collect_subset(self.difference(rhs))
+    }
+}
+
+impl<T, R, B> BitXor<&ArraySetFacade<T, R, B>> for &ArraySetFacade<T, R, B>
+where
+    T: Hash + Eq + Clone,
+    R: RawTableIter<(T, ())> + Default,
+    B: BuildHasher + Default,
+{
+    type Output = Result<ArraySetFacade<T, R, B>, CapacityError>;
+
+    /// Returns the symmetric difference of `self` and `rhs` as a new set.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CapacityError`] if the symmetric difference does not fit
+    /// into a set with the same capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::array_set;
+    /// use array_map::set::ArraySet;
+    ///
+    /// let a: ArraySet<_, 3> = array_set!(1, 2, 3);
+    /// let b: ArraySet<_, 3> = array_set!(2, 3, 4);
+    ///
+    /// let symmetric_difference = (&a ^ &b)?;
+    /// assert_eq!(symmetric_difference.len(), 2);
+    /// assert!(symmetric_difference.contains(&1) && symmetric_difference.contains(&4));
+    /// # Ok::<_, array_map::CapacityError>(())
+    /// ```
+    fn bitxor(self, rhs: &ArraySetFacade<T, R, B>) -> Self::Output {
+        self.symmetric_difference(rhs).cloned().try_collect()
+    }
+}
+
+#[cfg(feature = "panic")]
+impl<T, R, B> FromIterator<T> for ArraySetFacade<T, R, B>
+where
+    T: Hash + Eq,
+    R: RawTable<(T, ())> + Default,
+    B: BuildHasher + Default,
+{
+    #[track_caller]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            map: iter.into_iter().map(|value| (value, ())).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "panic")]
+impl<T, R, B> Extend<T> for ArraySetFacade<T, R, B>
+where
+    T: Hash + Eq,
+    R: RawTable<(T, ())>,
+    B: BuildHasher,
+{
+    #[track_caller]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.map.extend(iter.into_iter().map(|value| (value, ())));
+    }
+}
+
+#[cfg(feature = "panic")]
+impl<'a, T, R, B> Extend<&'a T> for ArraySetFacade<T, R, B>
+where
+    T: Hash + Eq + Copy,
+    R: RawTable<(T, ())>,
+    B: BuildHasher,
+{
+    #[track_caller]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
 #[cfg(all(test, feature = "ahash"))]
 mod tests {
     use super::*;
@@ -171,6 +939,41 @@ mod tests {
         assert_eq!(set.is_disjoint(&disjoint), true);
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_eq_hashset() {
+        use std::collections::HashSet;
+
+        let mut set = ArraySet::<u32, 31>::new();
+        let mut hash_set = HashSet::<u32>::new();
+
+        for i in 0..10 {
+            set.try_insert(i).unwrap();
+            hash_set.insert(i);
+        }
+
+        assert_eq!(set, hash_set);
+        assert_eq!(hash_set, set);
+
+        hash_set.insert(10);
+        assert_ne!(set, hash_set);
+    }
+
+    #[test]
+    fn test_set_eq_different_capacity() {
+        let mut a = ArraySet::<u32, 7>::new();
+        let mut b = ArraySet::<u32, 13>::new();
+
+        for i in 0..5 {
+            a.try_insert(i).unwrap();
+            b.try_insert(4 - i).unwrap();
+        }
+
+        assert_eq!(a.set_eq(&b), true);
+        b.remove(&0);
+        assert_eq!(a.set_eq(&b), false);
+    }
+
     #[test]
     fn test_is_disjoint() {
         // invariant: a.is_disjoint(&b) == b.is_disjoint(&a)
@@ -209,4 +1012,136 @@ mod tests {
             [None, Some(Key::A), Some(Key::B), Some(Key::C), Some(Key::D)]
         );
     }
+
+    #[test]
+    fn test_hash_ignores_order() {
+        let mut a = ArraySet::<u32, 7>::new();
+        let mut b = ArraySet::<u32, 7>::new();
+
+        for i in 0..5 {
+            a.try_insert(i).unwrap();
+            b.try_insert(4 - i).unwrap();
+        }
+
+        let mut memo = crate::ArrayMap::<ArraySet<u32, 7>, &str, 3>::new();
+        memo.try_insert(a, "cached").unwrap();
+
+        assert_eq!(memo.get(&b), Some(&"cached"));
+        b.remove(&0);
+        assert_eq!(memo.get(&b), None);
+    }
+
+    #[test]
+    fn test_hash_ignores_the_set_hasher() {
+        let hash = |set: &ArraySet<u32, 7, ahash::RandomState>| {
+            crate::DefaultHashBuilder::default().hash_one(set)
+        };
+
+        let mut a = ArraySet::with_hasher(ahash::RandomState::with_seeds(1, 2, 3, 4));
+        let mut b = ArraySet::with_hasher(ahash::RandomState::with_seeds(5, 6, 7, 8));
+
+        for i in 0..5 {
+            a.try_insert(i).unwrap();
+            b.try_insert(4 - i).unwrap();
+        }
+
+        assert_eq!(a, b);
+        assert_eq!(hash(&a), hash(&b));
+    }
+
+    #[test]
+    fn test_ord_compares_sorted_values() {
+        let mut a = ArraySet::<u32, 7>::new();
+        let mut b = ArraySet::<u32, 5>::new();
+        let mut c = ArraySet::<u32, 7>::new();
+
+        for i in [5, 1, 3] {
+            a.try_insert(i).unwrap();
+            c.try_insert(i).unwrap();
+        }
+        for i in [3, 1, 4] {
+            b.try_insert(i).unwrap();
+        }
+        c.try_insert(0).unwrap();
+
+        // [1, 3, 5] and [1, 3, 4]
+        assert_eq!(a.sorted::<7>().as_slice(), &[&1, &3, &5]);
+        assert_eq!(a.cmp(&a.clone()), Ordering::Equal);
+        // [1, 3, 5] and [0, 1, 3, 5]
+        assert_eq!(a.cmp(&c), Ordering::Greater);
+        assert!(b.sorted::<5>() < a.sorted::<5>());
+    }
+
+    #[test]
+    #[cfg(feature = "panic")]
+    fn test_panicking_api() {
+        let mut set: ArraySet<_, 4> = [Key::A, Key::B].into_iter().collect();
+        set.extend([Key::B, Key::C]);
+
+        assert_eq!(set.insert(Key::D), true);
+        assert_eq!(set.insert(Key::A), false);
+        assert_eq!(set.len(), 4);
+
+        let mut numbers: ArraySet<u8, 3> = ArraySet::new();
+        numbers.extend(&[1, 2, 1]);
+        assert_eq!(numbers.len(), 2);
+    }
+
+    #[test]
+    #[cfg(feature = "panic")]
+    #[should_panic(expected = "capacity 2")]
+    fn test_extend_overflow_panics() {
+        let mut set: ArraySet<_, 2> = ArraySet::new();
+        set.extend([Key::A, Key::B, Key::C]);
+    }
+
+    #[test]
+    #[cfg(feature = "generic_const_exprs")]
+    fn test_union_intersection_capacity() {
+        let mut a: ArraySet<_, 3> = ArraySet::new();
+        let mut b: ArraySet<_, 2> = ArraySet::new();
+        for key in [Key::A, Key::B, Key::C] {
+            a.try_insert(key).unwrap();
+        }
+        for key in [Key::C, Key::D] {
+            b.try_insert(key).unwrap();
+        }
+
+        let intersection = a.clone().intersection_into_smaller(&b);
+        assert_eq!(intersection.capacity(), 2);
+        assert_eq!(intersection.iter().try_collect::<[_; 1]>(), Ok([&Key::C]));
+
+        let union = a.union_into_larger(b);
+        assert_eq!(union.capacity(), 5);
+        assert_eq!(union.len(), 4);
+        assert!(Key::variants().into_iter().all(|key| union.contains(&key)));
+    }
+
+    #[test]
+    fn test_operators() {
+        let a: ArraySet<_, 4> = [Key::A, Key::B, Key::C].into_iter().try_collect().unwrap();
+        let mut b: ArraySet<Key, 4> = ArraySet::new();
+        b.try_extend([Key::C, Key::D]).unwrap();
+
+        assert_eq!((&a | &b).unwrap().len(), 4);
+        assert_eq!((&a & &b).iter().try_collect::<[_; 1]>(), Ok([&Key::C]));
+        assert_eq!((&b - &a).iter().try_collect::<[_; 1]>(), Ok([&Key::D]));
+
+        let xor = (&a ^ &b).unwrap();
+        assert_eq!(xor.len(), 3);
+        assert!(!xor.contains(&Key::C));
+
+        let mut full = (&a | &b).unwrap();
+        full.retain(|key| *key != Key::A);
+        assert_eq!(
+            (&full | &a),
+            Ok([Key::B, Key::C, Key::D, Key::A].into_iter().try_collect().unwrap())
+        );
+
+        let mut small: ArraySet<Key, 2> = ArraySet::new();
+        assert_eq!(
+            small.try_extend([Key::A, Key::B, Key::C]),
+            Err(CapacityError::new(2, 3))
+        );
+    }
 }
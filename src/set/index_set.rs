@@ -0,0 +1,373 @@
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::hash::{BuildHasher, Hash};
+use core::ops::RangeBounds;
+
+use crate::array_vec::{ArrayVec, VecStorage};
+use crate::map::IndexOutOfBoundsError;
+use crate::map::iter::{self, MapLeftIter};
+use crate::raw::ArrayIndexTable;
+use crate::set::ArraySetFacade;
+use crate::DefaultHashBuilder;
+
+/// An [`ArraySetFacade`] that keeps the insertion order of its values.
+///
+/// Like the [`IndexMap`](crate::IndexMap) it is based on, the set is only
+/// [`Copy`] if its values are stored in a [`CopyArrayVec`] and the values
+/// and the hasher are [`Copy`].
+///
+/// [`CopyArrayVec`]: crate::array_vec::CopyArrayVec
+pub type IndexSet<T, const N: usize, B = DefaultHashBuilder, E = ArrayVec<(T, ()), N>> =
+    ArraySetFacade<T, ArrayIndexTable<(T, ()), N, E>, B>;
+
+pub type DrainRange<'a, T, B, const N: usize> =
+    MapLeftIter<(T, ()), iter::DrainRange<'a, T, (), B, N>>;
+
+impl<T, const N: usize, B: BuildHasher, E> IndexSet<T, N, B, E>
+where
+    T: Hash + Eq,
+    E: VecStorage<(T, ()), N>,
+{
+    /// Returns the value at the index. If the index is larger than or equal to
+    /// the set's length, `None` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::index_set;
+    ///
+    /// let set = index_set!("b", "a", "c");
+    ///
+    /// assert_eq!(set.get_index(0), Some(&"b"));
+    /// assert_eq!(set.get_index(2), Some(&"c"));
+    /// assert_eq!(set.get_index(3), None);
+    /// ```
+    #[must_use]
+    pub fn get_index(&self, index: usize) -> Option<&T> {
+        self.map.get_entry_at(index).map(|(value, ())| value)
+    }
+
+    /// Returns the index of the value in the set, if it is present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::index_set;
+    ///
+    /// let set = index_set!("b", "a", "c");
+    ///
+    /// assert_eq!(set.get_index_of("a"), Some(1));
+    /// assert_eq!(set.get_index_of("d"), None);
+    /// ```
+    #[must_use]
+    pub fn get_index_of<Q>(&self, value: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get_index_of(value)
+    }
+
+    /// Removes the value from the set and shifts down all values after it.
+    /// Returns whether the value was present in the set.
+    ///
+    /// This preserves the order of the values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::index_set;
+    /// use array_map::set::SetIter;
+    ///
+    /// let mut set = index_set!(1, 2, 3, 4);
+    ///
+    /// assert_eq!(set.shift_remove(&2), true);
+    /// assert_eq!(set.shift_remove(&2), false);
+    /// assert_eq!(set.iter().collect::<Vec<_>>(), vec![&1, &3, &4]);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// O(n)
+    pub fn shift_remove<Q>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.shift_remove_entry(value).is_some()
+    }
+
+    /// Removes the value at the index by swapping it with the last value and
+    /// returns it.
+    ///
+    /// This does not preserve the order of the values, but is O(1).
+    ///
+    /// `None` is returned if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::index_set;
+    /// use array_map::set::SetIter;
+    ///
+    /// let mut set = index_set!(1, 2, 3, 4);
+    ///
+    /// assert_eq!(set.swap_remove_index(0), Some(1));
+    /// assert_eq!(set.swap_remove_index(3), None);
+    /// assert_eq!(set.iter().collect::<Vec<_>>(), vec![&4, &2, &3]);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// O(1)
+    pub fn swap_remove_index(&mut self, index: usize) -> Option<T> {
+        self.map.swap_remove_index(index).map(|(value, ())| value)
+    }
+
+    /// Removes the last value from the set and returns it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::index_set;
+    ///
+    /// let mut set = index_set!('a', 'b');
+    ///
+    /// assert_eq!(set.pop(), Some('b'));
+    /// assert_eq!(set.pop(), Some('a'));
+    /// assert_eq!(set.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        self.map.pop().map(|(value, ())| value)
+    }
+
+    /// Shortens the set, keeping the first `n` values and removing the rest.
+    ///
+    /// If `n` is greater than `self.len` this does nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::index_set;
+    /// use array_map::set::SetIter;
+    ///
+    /// let mut set = index_set!('a', 'b', 'c');
+    /// set.truncate(1);
+    ///
+    /// assert_eq!(set.iter().collect::<Vec<_>>(), vec![&'a']);
+    /// ```
+    pub fn truncate(&mut self, n: usize) {
+        self.map.truncate(n);
+    }
+
+    /// Clears the set in the given index range, returning all values in that
+    /// range as an iterator.
+    ///
+    /// This shifts down all the values following the drained range to fill the
+    /// gap.
+    ///
+    /// # Panics
+    ///
+    /// If the starting point is greater than the end point of if the end point
+    /// is greater than the length of the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::index_set;
+    /// use array_map::set::SetIter;
+    ///
+    /// let mut set = index_set!(1, 2, 3, 4);
+    ///
+    /// assert_eq!(set.drain_range(1..3).collect::<Vec<_>>(), vec![2, 3]);
+    /// assert_eq!(set.iter().collect::<Vec<_>>(), vec![&1, &4]);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// O(n)
+    pub fn drain_range<R: RangeBounds<usize>>(&mut self, range: R) -> DrainRange<'_, T, B, N> {
+        MapLeftIter::new(self.map.drain_range(range))
+    }
+
+    /// Swaps the position of the two values `a` and `b`.
+    ///
+    /// # Panics
+    ///
+    /// If either `a` or `b` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::index_set;
+    ///
+    /// let mut set = index_set!("apple", "tree", "cake");
+    /// set.swap_indices(0, 2);
+    ///
+    /// assert_eq!(set.get_index(0), Some(&"cake"));
+    /// assert_eq!(set.get_index(2), Some(&"apple"));
+    /// ```
+    pub fn swap_indices(&mut self, a: usize, b: usize) {
+        self.map.swap_indices(a, b);
+    }
+
+    /// Swaps the position of the two values `a` and `b`.
+    ///
+    /// # Errors
+    ///
+    /// If either `a` or `b` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_map::index_set;
+    ///
+    /// let mut set = index_set!("apple", "tree");
+    ///
+    /// assert_eq!(set.try_swap_indices(0, 1), Ok(()));
+    /// assert!(set.try_swap_indices(0, 2).is_err());
+    /// assert_eq!(set.get_index(0), Some(&"tree"));
+    /// ```
+    pub fn try_swap_indices(&mut self, a: usize, b: usize) -> Result<(), IndexOutOfBoundsError> {
+        self.map.try_swap_indices(a, b)
+    }
+}
+
+/// Sets are compared by their values in ascending order, the insertion order
+/// is ignored.
+///
+/// # Complexity
+///
+/// O(n log n)
+impl<T, B, const N: usize, E> PartialOrd for IndexSet<T, N, B, E>
+where
+    T: Hash + Ord,
+    B: BuildHasher,
+    E: VecStorage<(T, ()), N>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T, B, const N: usize, E> Ord for IndexSet<T, N, B, E>
+where
+    T: Hash + Ord,
+    B: BuildHasher,
+    E: VecStorage<(T, ()), N>,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.sorted::<N>().cmp(&other.sorted::<N>())
+    }
+}
+
+#[cfg(all(test, feature = "ahash"))]
+mod tests {
+    use super::*;
+
+    use crate::array_vec::CopyArrayVec;
+    use crate::ext::IteratorExt;
+    use crate::index_set;
+    use crate::set::{Set, SetIter};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_set_operations_keep_insertion_order() {
+        let a: IndexSet<_, 5> = index_set!(@capacity 5, 5, 1, 4, 2);
+        let b: IndexSet<_, 4> = index_set!(@capacity 4, 3, 4, 0, 5);
+
+        assert_eq!(
+            a.union(&b).copied().try_collect::<[_; 6]>(),
+            Ok([5, 1, 4, 2, 3, 0])
+        );
+        assert_eq!(
+            a.difference(&b).copied().try_collect::<[_; 2]>(),
+            Ok([1, 2])
+        );
+        assert_eq!(
+            a.intersection(&b).copied().try_collect::<[_; 2]>(),
+            Ok([5, 4])
+        );
+        assert_eq!(
+            a.symmetric_difference(&b).copied().try_collect::<[_; 4]>(),
+            Ok([1, 2, 3, 0])
+        );
+    }
+
+    #[test]
+    fn test_positions_after_removal() {
+        let mut set: IndexSet<_, 6> = index_set!(@capacity 6, 'a', 'b', 'c', 'd', 'e');
+
+        assert!(set.shift_remove(&'b'));
+        assert_eq!(set.get_index_of(&'c'), Some(1));
+        assert_eq!(set.swap_remove_index(0), Some('a'));
+        assert_eq!(set.get_index_of(&'e'), Some(0));
+
+        set.try_insert('f').unwrap();
+        assert_eq!(set.drain_range(..1).try_collect::<[_; 1]>(), Ok(['e']));
+        assert_eq!(set.iter().try_collect::<[_; 3]>(), Ok([&'c', &'d', &'f']));
+        assert_eq!(
+            (0..set.len())
+                .map(|index| set.get_index(index))
+                .try_collect::<[_; 3]>(),
+            Ok([Some(&'c'), Some(&'d'), Some(&'f')])
+        );
+    }
+
+    #[test]
+    fn test_drain_filter_keeps_positions_valid() {
+        let mut set: IndexSet<u32, 17> = (0..10).try_collect().unwrap();
+
+        let mut drained: [Option<u32>; 10] = set
+            .drain_filter(|value| value % 3 != 1)
+            .try_collect()
+            .unwrap();
+        drained.sort_unstable();
+
+        assert_eq!(
+            drained,
+            [
+                None,
+                None,
+                None,
+                Some(0),
+                Some(2),
+                Some(3),
+                Some(5),
+                Some(6),
+                Some(8),
+                Some(9),
+            ]
+        );
+        assert_eq!(set.iter().try_collect::<[_; 3]>(), Ok([&1, &4, &7]));
+        for value in 0..10 {
+            assert_eq!(set.contains(&value), value % 3 == 1);
+        }
+        assert_eq!(set.get_index_of(&7), Some(2));
+    }
+
+    #[test]
+    fn test_copy_storage() {
+        #[derive(Clone, Copy, Default)]
+        struct CopyState;
+
+        impl BuildHasher for CopyState {
+            type Hasher = ahash::AHasher;
+
+            fn build_hasher(&self) -> Self::Hasher {
+                ahash::AHasher::default()
+            }
+        }
+
+        let mut set: IndexSet<u32, 7, CopyState, CopyArrayVec<(u32, ()), 7>> =
+            (0..4).try_collect().unwrap();
+        let copy = set;
+
+        assert!(set.shift_remove(&1));
+        set.try_insert(9).unwrap();
+
+        assert_eq!(set.iter().try_collect::<[_; 4]>(), Ok([&0, &2, &3, &9]));
+        assert_eq!(copy.iter().try_collect::<[_; 4]>(), Ok([&0, &1, &2, &3]));
+        assert_eq!(copy.get_index_of(&3), Some(3));
+    }
+}
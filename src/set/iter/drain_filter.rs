@@ -0,0 +1,32 @@
+use crate::map::iter::{self as map_iter, MapLeftIter};
+
+/// A draining iterator over values of an `ArraySet` which satisfy the
+/// predicate `F`.
+///
+/// This is created by [`ArraySetFacade::drain_filter`]. See its
+/// documentation for more.
+///
+/// [`ArraySetFacade::drain_filter`]: crate::set::ArraySetFacade::drain_filter
+pub type DrainFilter<'a, T, F, R, B> =
+    MapLeftIter<(T, ()), map_iter::DrainFilter<'a, T, (), F, R, B>>;
+
+#[cfg(all(test, feature = "ahash"))]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::array_set;
+    use crate::ext::IteratorExt;
+
+    #[test]
+    fn test_drain_filter_drop() {
+        let mut set = array_set!(1, 2, 3, 4, 5, 6);
+
+        set.drain_filter(|value| value % 2 == 0);
+
+        let mut remaining = set.iter().copied().try_collect::<[i32; 3]>().unwrap();
+        remaining.sort_unstable();
+        assert_eq!(remaining, [1, 3, 5]);
+        assert!(set.contains(&5));
+        assert!(!set.contains(&4));
+    }
+}
@@ -1,9 +1,11 @@
 mod difference;
+mod drain_filter;
 mod intersection;
 mod symmetric_difference;
 mod union;
 
 pub use difference::*;
+pub use drain_filter::*;
 pub use intersection::*;
 pub use symmetric_difference::*;
 pub use union::*;
@@ -0,0 +1,307 @@
+/// Creates an [`ArraySet`] from a list of values.
+///
+/// The macro supports the same arguments as [`array_map!`]: the capacity is
+/// the number of values, unless it is specified with `@capacity N` or
+/// inferred from the context with `@infer`. Duplicate literal values are
+/// rejected at compile time.
+///
+/// [`ArraySet`]: crate::set::ArraySet
+///
+/// # Examples
+///
+/// ```
+/// use array_map::array_set;
+/// use array_map::set::Set;
+///
+/// let set = array_set!(1, 2, 3);
+/// assert_eq!(set.capacity(), 3);
+/// assert!(set.contains(&2));
+///
+/// let mut set = array_set!(@capacity 3, "a");
+/// set.try_insert("b")?;
+/// assert_eq!(set.len(), 2);
+/// # Ok::<_, array_map::CapacityError>(())
+/// ```
+///
+/// ```compile_fail
+/// use array_map::array_set;
+///
+/// let set = array_set!(1, 2, 1);
+/// ```
+#[macro_export]
+macro_rules! array_set {
+    // replaces `_t` with the provided expression `e`
+    (@replace $_t:tt $e:expr) => { $e };
+    // counts the number of tokens and returns a const expr
+    (@count $($x:expr),*) => {
+        <[()]>::len(&[$( $crate::array_set!(@replace $x ()) ),*])
+    };
+    // rejects duplicate literal values, because the pattern of a duplicate value is unreachable
+    (@check_duplicates $first:literal $(, $value:literal )*) => {
+        #[deny(unreachable_patterns)]
+        #[allow(clippy::manual_range_patterns)]
+        let _ = ::core::matches!($first, $first $( | $value )*);
+    };
+    // literal values are checked for duplicates, before they are passed to the other rules
+    ( @infer, $( @build_hasher => $bh:expr, )? $( $value:literal ),+ $(,)? ) => {
+        {
+            $crate::array_set!(@check_duplicates $( $value ),+);
+            $crate::array_set!( @infer, $( @build_hasher => $bh, )? $( @unchecked $value ),+)
+        }
+    };
+    ( @capacity $capacity:expr $(, @build_hasher => $bh:expr)? $(, $value:literal )+ $(,)? ) => {
+        {
+            $crate::array_set!(@check_duplicates $( $value ),+);
+            $crate::array_set!( @capacity $capacity $(, @build_hasher => $bh)? $(, @unchecked $value )+)
+        }
+    };
+    ( $( @build_hasher => $bh:expr, )? $( $value:literal ),+ $(,)? ) => {
+        {
+            $crate::array_set!(@check_duplicates $( $value ),+);
+            $crate::array_set!( $( @build_hasher => $bh, )? $( @unchecked $value ),+)
+        }
+    };
+    // removes the marker of already checked values
+    ( @infer, $( @build_hasher => $bh:expr, )? $( @unchecked $value:literal ),+ ) => {
+        $crate::array_set!( @infer, $( @build_hasher => $bh, )? $( ($value) ),+)
+    };
+    ( @capacity $capacity:expr $(, @build_hasher => $bh:expr)? $(, @unchecked $value:literal )+ ) => {
+        $crate::array_set!( @capacity $capacity $(, @build_hasher => $bh)? $(, ($value) )+)
+    };
+    ( $( @build_hasher => $bh:expr, )? $( @unchecked $value:literal ),+ ) => {
+        $crate::array_set!( $( @build_hasher => $bh, )? $( ($value) ),+)
+    };
+    // To support trailing commas in the macro
+    ( @infer, $( @build_hasher => $bh:expr, )? $( $value:expr, )+ ) => {
+        $crate::array_set!( @infer, $( @build_hasher => $bh, )? $( $value ),+)
+    };
+    ( @capacity $capacity:expr $(, @build_hasher => $bh:expr)? $(, $value:expr )+ , ) => {
+        $crate::array_set!( @capacity $capacity $(, @build_hasher => $bh)? $(, $value )+)
+    };
+    ( $( @build_hasher => $bh:expr, )? $( $value:expr, )+ ) => {
+        $crate::array_set!( $( @build_hasher => $bh, )? $( $value ),+)
+    };
+    ( @helper_construct $bh:expr ) => {
+        $crate::set::ArraySet::with_build_hasher($bh)
+    };
+    ( @helper_construct ) => {
+        $crate::set::ArraySet::with_build_hasher($crate::default_build_hasher!())
+    };
+    ( @infer, $( @build_hasher => $bh:expr, )? $( $value:expr ),* ) => {
+        {
+            // the closure is only redundant if no values are inserted
+            #[allow(clippy::redundant_closure_call)]
+            let result: Result<_, $crate::CapacityError> = (|| {
+                let mut _set = $crate::array_set!( @helper_construct $( $bh )? );
+
+                $(
+                    $crate::set::Set::try_insert(&mut _set, $value)?;
+                )*
+
+                Ok(_set)
+            })();
+
+            result
+        }
+    };
+    ( @capacity $capacity:expr $(, @build_hasher => $bh:expr)? $(, $value:expr )* ) => {
+        {
+            const {
+                ::core::assert!(
+                    $crate::array_set!(@count $($value),*) <= $capacity,
+                    "`array_set` macro has more values than the specified capacity"
+                );
+            }
+
+            let _set: $crate::set::ArraySet<_, { $capacity }, _> = $crate::array_set!(
+                @infer,
+                $( @build_hasher => $bh, )?
+                $( $value ),*
+            ).expect("`array_set` macro does not count correctly!");
+
+            _set
+        }
+    };
+    ( $( @build_hasher => $bh:expr, )? $( $value:expr ),* ) => {
+        {
+            let _set: $crate::set::ArraySet<_, { $crate::array_set!(@count $($value),*) }, _> = $crate::array_set!(
+                @infer,
+                $( @build_hasher => $bh, )?
+                $( $value ),*
+            ).expect("`array_set` macro does not count correctly!");
+
+            _set
+        }
+    };
+}
+
+/// Creates an [`IndexSet`] from a list of values, which keeps the order of
+/// the values.
+///
+/// The macro supports the same arguments as [`array_set!`].
+///
+/// [`IndexSet`]: crate::set::IndexSet
+///
+/// # Examples
+///
+/// ```
+/// use array_map::index_set;
+/// use array_map::set::SetIter;
+///
+/// let set = index_set!(@capacity 4, 'c', 'a', 'b');
+///
+/// assert_eq!(set.capacity(), 4);
+/// assert_eq!(set.iter().collect::<Vec<_>>(), vec![&'c', &'a', &'b']);
+/// ```
+#[macro_export]
+macro_rules! index_set {
+    // replaces `_t` with the provided expression `e`
+    (@replace $_t:tt $e:expr) => { $e };
+    // counts the number of tokens and returns a const expr
+    (@count $($x:expr),*) => {
+        <[()]>::len(&[$( $crate::index_set!(@replace $x ()) ),*])
+    };
+    // rejects duplicate literal values, because the pattern of a duplicate value is unreachable
+    (@check_duplicates $first:literal $(, $value:literal )*) => {
+        #[deny(unreachable_patterns)]
+        #[allow(clippy::manual_range_patterns)]
+        let _ = ::core::matches!($first, $first $( | $value )*);
+    };
+    // literal values are checked for duplicates, before they are passed to the other rules
+    ( @infer, $( @build_hasher => $bh:expr, )? $( $value:literal ),+ $(,)? ) => {
+        {
+            $crate::index_set!(@check_duplicates $( $value ),+);
+            $crate::index_set!( @infer, $( @build_hasher => $bh, )? $( @unchecked $value ),+)
+        }
+    };
+    ( @capacity $capacity:expr $(, @build_hasher => $bh:expr)? $(, $value:literal )+ $(,)? ) => {
+        {
+            $crate::index_set!(@check_duplicates $( $value ),+);
+            $crate::index_set!( @capacity $capacity $(, @build_hasher => $bh)? $(, @unchecked $value )+)
+        }
+    };
+    ( $( @build_hasher => $bh:expr, )? $( $value:literal ),+ $(,)? ) => {
+        {
+            $crate::index_set!(@check_duplicates $( $value ),+);
+            $crate::index_set!( $( @build_hasher => $bh, )? $( @unchecked $value ),+)
+        }
+    };
+    // removes the marker of already checked values
+    ( @infer, $( @build_hasher => $bh:expr, )? $( @unchecked $value:literal ),+ ) => {
+        $crate::index_set!( @infer, $( @build_hasher => $bh, )? $( ($value) ),+)
+    };
+    ( @capacity $capacity:expr $(, @build_hasher => $bh:expr)? $(, @unchecked $value:literal )+ ) => {
+        $crate::index_set!( @capacity $capacity $(, @build_hasher => $bh)? $(, ($value) )+)
+    };
+    ( $( @build_hasher => $bh:expr, )? $( @unchecked $value:literal ),+ ) => {
+        $crate::index_set!( $( @build_hasher => $bh, )? $( ($value) ),+)
+    };
+    // To support trailing commas in the macro
+    ( @infer, $( @build_hasher => $bh:expr, )? $( $value:expr, )+ ) => {
+        $crate::index_set!( @infer, $( @build_hasher => $bh, )? $( $value ),+)
+    };
+    ( @capacity $capacity:expr $(, @build_hasher => $bh:expr)? $(, $value:expr )+ , ) => {
+        $crate::index_set!( @capacity $capacity $(, @build_hasher => $bh)? $(, $value )+)
+    };
+    ( $( @build_hasher => $bh:expr, )? $( $value:expr, )+ ) => {
+        $crate::index_set!( $( @build_hasher => $bh, )? $( $value ),+)
+    };
+    ( @helper_construct $bh:expr ) => {
+        $crate::set::IndexSet::with_build_hasher($bh)
+    };
+    ( @helper_construct ) => {
+        $crate::set::IndexSet::with_build_hasher($crate::default_build_hasher!())
+    };
+    ( @infer, $( @build_hasher => $bh:expr, )? $( $value:expr ),* ) => {
+        {
+            // the closure is only redundant if no values are inserted
+            #[allow(clippy::redundant_closure_call)]
+            let result: Result<_, $crate::CapacityError> = (|| {
+                let mut _set = $crate::index_set!( @helper_construct $( $bh )? );
+
+                $(
+                    $crate::set::Set::try_insert(&mut _set, $value)?;
+                )*
+
+                Ok(_set)
+            })();
+
+            result
+        }
+    };
+    ( @capacity $capacity:expr $(, @build_hasher => $bh:expr)? $(, $value:expr )* ) => {
+        {
+            const {
+                ::core::assert!(
+                    $crate::index_set!(@count $($value),*) <= $capacity,
+                    "`index_set` macro has more values than the specified capacity"
+                );
+            }
+
+            let _set: $crate::set::IndexSet<_, { $capacity }, _> = $crate::index_set!(
+                @infer,
+                $( @build_hasher => $bh, )?
+                $( $value ),*
+            ).expect("`index_set` macro does not count correctly!");
+
+            _set
+        }
+    };
+    ( $( @build_hasher => $bh:expr, )? $( $value:expr ),* ) => {
+        {
+            let _set: $crate::set::IndexSet<_, { $crate::index_set!(@count $($value),*) }, _> = $crate::index_set!(
+                @infer,
+                $( @build_hasher => $bh, )?
+                $( $value ),*
+            ).expect("`index_set` macro does not count correctly!");
+
+            _set
+        }
+    };
+}
+
+#[cfg(all(test, feature = "ahash"))]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::ext::IteratorExt;
+    use crate::set::{ArraySet, DefaultHashBuilder, IndexSet, Set};
+
+    #[test]
+    fn test_array_set_macro() {
+        let set = array_set!["a", "b", "c",];
+        let mut expected = ArraySet::<_, 3>::new();
+        for value in ["c", "b", "a"] {
+            expected.try_insert(value).unwrap();
+        }
+
+        assert_eq!(set, expected);
+    }
+
+    #[test]
+    fn test_array_set_macro_empty() {
+        let set: ArraySet<u8, 0> = array_set!();
+        assert!(set.is_empty());
+
+        let set: ArraySet<u8, 2> = array_set!(@capacity 2);
+        assert_eq!(set.capacity(), 2);
+    }
+
+    #[test]
+    fn test_array_set_macro_flags() {
+        let value = 2;
+        let set = array_set!(@capacity 4, @build_hasher => DefaultHashBuilder::default(), 1, value);
+        assert_eq!(set.capacity(), 4);
+        assert!(set.contains(&1) && set.contains(&2));
+
+        let set: ArraySet<_, 2> = array_set!(@infer, 1, 2, 3).unwrap_or_default();
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_index_set_macro() {
+        let set: IndexSet<_, 3> = index_set!(3, 1, 2);
+
+        assert_eq!(set.iter().copied().try_collect::<[_; 3]>(), Ok([3, 1, 2]));
+    }
+}
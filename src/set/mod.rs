@@ -1,8 +1,11 @@
 mod array_set_facade;
+mod index_set;
 pub mod iter;
+mod macros;
 mod set;
 
 pub use crate::DefaultHashBuilder;
 
 pub use array_set_facade::*;
+pub use index_set::*;
 pub use set::*;
@@ -196,6 +196,18 @@ pub trait SetIter<T>: Set<T> + IntoIterator<Item = T> {
     {
         other.is_subset(self)
     }
+
+    /// Returns `true` if both sets contain the same values.
+    ///
+    /// The sets can be of different types, for example an `ArraySet` can be
+    /// compared with a `HashSet` or an `ArraySet` with a different capacity.
+    #[must_use]
+    fn set_eq<S: Set<T>>(&self, other: &S) -> bool
+    where
+        T: Hash + Eq,
+    {
+        self.len() == other.len() && self.is_subset(other)
+    }
 }
 
 #[cfg(feature = "std")]
@@ -0,0 +1,55 @@
+use core::hash::{Hash, Hasher};
+
+/// A [`Hasher`] implementing FNV-1a, which does not depend on any seed.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct FnvHasher(u64);
+
+impl Default for FnvHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for FnvHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+/// Feeds the values into the hasher, so that the result does not depend on
+/// the order of the values.
+///
+/// Each value is hashed on its own and the hashes are combined with a
+/// commutative operation.
+pub(crate) fn hash_unordered<T: Hash, H: Hasher>(values: impl Iterator<Item = T>, state: &mut H) {
+    let mut count = 0_usize;
+    let mut sum = 0_u64;
+
+    for value in values {
+        let mut hasher = FnvHasher::default();
+        value.hash(&mut hasher);
+
+        count += 1;
+        sum = sum.wrapping_add(mix(hasher.finish()));
+    }
+
+    state.write_usize(count);
+    state.write_u64(sum);
+}
+
+/// The finalizer of `MurmurHash3`, which spreads the bits of the FNV hash, so
+/// that the sum of the hashes does not collide as easily.
+const fn mix(mut hash: u64) -> u64 {
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+    hash ^ (hash >> 33)
+}
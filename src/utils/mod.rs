@@ -1,5 +1,7 @@
+mod fnv;
 mod iter_circular;
 
+pub(crate) use fnv::*;
 pub(crate) use iter_circular::*;
 
 use core::borrow::Borrow;
@@ -20,9 +22,26 @@ where
     hasher.finish()
 }
 
+/// Panics with the message used by the panicking APIs of the `panic` feature
+/// when an element is inserted into a full collection.
+#[cfg(feature = "panic")]
+#[cold]
+#[track_caller]
+pub(crate) fn capacity_overflow(capacity: usize) -> ! {
+    panic!("capacity overflow: cannot insert into a full collection with capacity {capacity}")
+}
+
 #[must_use]
 pub(crate) fn adjust_hash<const N: usize>(hash: u64) -> usize {
-    (hash % (N as u64)) as usize
+    adjust_hash_to(hash, N)
+}
+
+/// Like [`adjust_hash`], but for a capacity that is only known at runtime.
+#[must_use]
+// the remainder is smaller than the capacity, so it fits in an usize
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) const fn adjust_hash_to(hash: u64, capacity: usize) -> usize {
+    (hash % (capacity as u64)) as usize
 }
 
 pub(crate) fn key_hasher<K, V, B>(build_hasher: &B) -> impl Fn(&(K, V)) -> u64 + '_
@@ -68,6 +87,32 @@ macro_rules! unreachable_unchecked {
     };
 }
 
+/// Creates the [`DefaultHashBuilder`] for the collection macros.
+///
+/// Whether the `ahash` feature is enabled is decided when this crate is
+/// compiled, so the exported macros must not check the feature themselves.
+///
+/// [`DefaultHashBuilder`]: crate::DefaultHashBuilder
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "ahash")]
+macro_rules! default_build_hasher {
+    () => {
+        <$crate::DefaultHashBuilder as ::core::default::Default>::default()
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "ahash"))]
+macro_rules! default_build_hasher {
+    () => {
+        ::core::compile_error!(
+            "`ahash` feature is disabled, so a build_hasher must be specified explicitly!"
+        )
+    };
+}
+
 /// Asserts that `x` is always `true`.
 ///
 /// # Safety
@@ -184,7 +229,7 @@ impl<T, const N: usize> ArrayExt<T, N> for [T; N] {
         R: Try<Output = [U; N], Residual = X::Residual>,
         F: FnMut(T) -> X,
     {
-        let mut array: [MaybeUninit<U>; N] = MaybeUninit::uninit_array();
+        let mut array: [MaybeUninit<U>; N] = [const { MaybeUninit::uninit() }; N];
         let mut iterator = self.into_iter();
 
         for item in array.iter_mut() {
@@ -205,3 +250,9 @@ impl<T, const N: usize> ArrayExt<T, N> for [T; N] {
 pub(crate) fn none_array<T, const N: usize>() -> [Option<T>; N] {
     [(); N].map(|_| None)
 }
+
+#[must_use]
+pub(crate) const fn uninit_array<T, const N: usize>() -> [MaybeUninit<T>; N] {
+    // SAFETY: an array of `MaybeUninit` does not have to be initialized
+    unsafe { MaybeUninit::<[MaybeUninit<T>; N]>::uninit().assume_init() }
+}